@@ -0,0 +1,82 @@
+use cosmwasm_crypto::secp256k1_verify;
+use cosmwasm_std::Binary;
+use sha2::{Digest, Sha256};
+
+use crate::cosmos::address_from_pubkey;
+use crate::errors::ContractError;
+
+/// Builds the canonical amino JSON of an ADR-036 offchain `StdSignDoc` for `signer` and `data`:
+/// a single `sign/MsgSignData` message, empty chain id and memo, zero account number,
+/// sequence and fee. Keys are sorted and there is no whitespace, as produced by Keplr and the
+/// Ledger Cosmos app.
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/master/docs/architecture/adr-036-arbitrary-signature.md
+pub fn sign_doc(signer: &str, data: &[u8]) -> String {
+    format!(
+        concat!(
+            r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","#,
+            r#""msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"#,
+            r#""sequence":"0"}}"#
+        ),
+        Binary::from(data).to_base64(),
+        signer
+    )
+}
+
+/// Verifies an ADR-036 signature of `data` by `signer`, a bech32 account address.
+///
+/// The signature verifies if `public_key` derives to `signer` and the secp256k1 signature over
+/// `sha256(sign_doc(signer, data))` is valid.
+pub fn verify(
+    signer: &str,
+    data: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> Result<bool, ContractError> {
+    // Decoding also ensures that the address needs no escaping in the sign doc
+    let (prefix, _) = bech32::decode(signer).map_err(|err| ContractError::InvalidAddress {
+        msg: format!("Invalid signer address: {}", err),
+    })?;
+    let hash = Sha256::digest(sign_doc(signer, data).as_bytes());
+    let verifies = secp256k1_verify(&hash, signature, public_key)?;
+    Ok(verifies && address_from_pubkey(&prefix, public_key)? == signer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_doc_works() {
+        assert_eq!(
+            sign_doc("cosmos1h806c7khnvmjlywdrkdgk2vrayy2mmvf9rxk2r", b"hello"),
+            r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"aGVsbG8=","signer":"cosmos1h806c7khnvmjlywdrkdgk2vrayy2mmvf9rxk2r"}}],"sequence":"0"}"#
+        );
+    }
+
+    // cosmjs faucet test account (m/44'/118'/0'/0/0 of the mnemonic "economy stock theory ...").
+    // The signature was made with Python `cryptography` (RFC 6979, low s) over the sorted key,
+    // whitespace free JSON of the sign doc, as cosmjs `serializeSignDoc` produces it.
+    const EXTERNAL_SIGNER: &str = "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6";
+    const EXTERNAL_PUBLIC_KEY: &str = "A08EGB7ro1ORuFhjOnZcSgwYlpe0DSFjVNUIkNNQxwKQ";
+    const EXTERNAL_DATA: &[u8] =
+        b"Verify gateway owner cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6";
+    const EXTERNAL_SIGNATURE: &str =
+        "5F1eybtNbQgVku52xgbYF6UNfYIwmbONF5PFTmSnpRURQTsPxyd0TxEC0hKq3mc0Kw21hyb73ISf3knqCNdNHw==";
+
+    #[test]
+    fn verify_external_vector() {
+        let public_key = Binary::from_base64(EXTERNAL_PUBLIC_KEY).unwrap().0;
+        let signature = Binary::from_base64(EXTERNAL_SIGNATURE).unwrap().0;
+        assert!(verify(EXTERNAL_SIGNER, EXTERNAL_DATA, &signature, &public_key).unwrap());
+
+        // any change to the signed data fails
+        assert!(!verify(
+            EXTERNAL_SIGNER,
+            b"Verify gateway owner",
+            &signature,
+            &public_key
+        )
+        .unwrap());
+    }
+}
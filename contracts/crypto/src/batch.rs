@@ -0,0 +1,70 @@
+use crate::errors::ContractError;
+
+/// A message, its signature and the signer's public key
+pub type BatchEntry<'a> = (&'a [u8], &'a [u8], &'a [u8]);
+
+/// Pairs up messages, signatures and public keys of a batch. Three shapes are supported:
+/// - Equal number of messages, signatures, and public keys.
+/// - One message, and an equal number of signatures and public keys (multisig of a single message).
+/// - One public key, and an equal number of messages and signatures (many messages by one signer).
+///
+/// The entries are returned in signature order.
+pub fn batch_entries<'a>(
+    messages: &'a [&'a [u8]],
+    signatures: &'a [&'a [u8]],
+    public_keys: &'a [&'a [u8]],
+) -> Result<Vec<BatchEntry<'a>>, ContractError> {
+    let n = signatures.len();
+    if messages.len() == n && public_keys.len() == n {
+        Ok((0..n)
+            .map(|i| (messages[i], signatures[i], public_keys[i]))
+            .collect())
+    } else if messages.len() == 1 && public_keys.len() == n {
+        Ok((0..n)
+            .map(|i| (messages[0], signatures[i], public_keys[i]))
+            .collect())
+    } else if public_keys.len() == 1 && messages.len() == n {
+        Ok((0..n)
+            .map(|i| (messages[i], signatures[i], public_keys[0]))
+            .collect())
+    } else {
+        Err(ContractError::BatchErr {
+            msg: format!(
+                "Mismatched / erroneous number of messages ({}) / signatures ({}) / public keys ({})",
+                messages.len(),
+                n,
+                public_keys.len()
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_entries_shapes() {
+        let (m1, m2): (&[u8], &[u8]) = (b"m1", b"m2");
+        let (s1, s2): (&[u8], &[u8]) = (b"s1", b"s2");
+        let (k1, k2): (&[u8], &[u8]) = (b"k1", b"k2");
+
+        assert_eq!(
+            batch_entries(&[m1, m2], &[s1, s2], &[k1, k2]).unwrap(),
+            vec![(m1, s1, k1), (m2, s2, k2)]
+        );
+        assert_eq!(
+            batch_entries(&[m1], &[s1, s2], &[k1, k2]).unwrap(),
+            vec![(m1, s1, k1), (m1, s2, k2)]
+        );
+        assert_eq!(
+            batch_entries(&[m1, m2], &[s1, s2], &[k1]).unwrap(),
+            vec![(m1, s1, k1), (m2, s2, k1)]
+        );
+        assert_eq!(batch_entries(&[], &[], &[]).unwrap(), vec![]);
+
+        assert!(batch_entries(&[m1, m2], &[s1], &[k1]).is_err());
+        assert!(batch_entries(&[m1, m2], &[s1, s2], &[]).is_err());
+        assert!(batch_entries(&[], &[s1], &[k1]).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+use bech32::FromBase32;
+use cosmwasm_crypto::secp256k1_recover_pubkey;
+use ripemd160::Ripemd160;
+use sha2::{Digest, Sha256};
+
+use crate::errors::ContractError;
+use crate::secp256k1::{compress_pubkey, RECOVERABLE_SIGNATURE_LEN};
+
+/// Prefix of signed messages, preceded by its length
+const MESSAGE_MAGIC: &[u8] = b"\x18Bitcoin Signed Message:\n";
+
+/// Length of a public key hash
+pub const PUBKEY_HASH_LEN: usize = 20;
+
+/// Version bytes of P2PKH addresses on mainnet and testnet/regtest
+const P2PKH_VERSIONS: [u8; 2] = [0x00, 0x6f];
+
+/// Human readable parts of segwit addresses on mainnet, testnet and regtest
+const SEGWIT_HRPS: [&str; 3] = ["bc", "tb", "bcrt"];
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// An address that a single public key can sign for
+#[derive(Debug, PartialEq)]
+pub enum Address {
+    /// Pay to public key hash (legacy `1...` address)
+    P2pkh([u8; PUBKEY_HASH_LEN]),
+    /// Pay to witness public key hash (native segwit `bc1q...` address)
+    P2wpkh([u8; PUBKEY_HASH_LEN]),
+}
+
+/// Hashes a message the way Bitcoin Core's `signmessage` does:
+/// `sha256(sha256("\x18Bitcoin Signed Message:\n" || varint(len(message)) || message))`.
+pub fn message_hash(message: &[u8]) -> [u8; 32] {
+    let mut prefixed = MESSAGE_MAGIC.to_vec();
+    prefixed.extend_from_slice(&varint(message.len() as u64));
+    prefixed.extend_from_slice(message);
+    double_sha256(&prefixed)
+}
+
+/// `ripemd160(sha256(public_key))`
+pub fn hash160(public_key: &[u8]) -> [u8; PUBKEY_HASH_LEN] {
+    Ripemd160::digest(&Sha256::digest(public_key)).into()
+}
+
+/// Decodes a P2PKH or P2WPKH address of mainnet, testnet or regtest.
+pub fn decode_address(address: &str) -> Result<Address, ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidAddress {
+        msg: format!("Invalid Bitcoin address {}: {}", address, reason),
+    };
+    let is_segwit = SEGWIT_HRPS
+        .iter()
+        .any(|hrp| address.to_lowercase().starts_with(&format!("{}1", hrp)));
+    let (segwit, payload) = if is_segwit {
+        let (hrp, data) = bech32::decode(address).map_err(|err| invalid(&err.to_string()))?;
+        // The separator is the last `1`, so e.g. `bc1q...1...` passes the prefix check above
+        // with a human readable part of `bc1q...`
+        if !SEGWIT_HRPS.contains(&hrp.as_str()) {
+            return Err(invalid(&format!("unknown network {}", hrp)));
+        }
+        match data.split_first() {
+            Some((version, program)) if version.to_u8() == 0 => (
+                true,
+                Vec::<u8>::from_base32(program).map_err(|err| invalid(&err.to_string()))?,
+            ),
+            _ => return Err(invalid("only P2PKH and P2WPKH addresses are supported")),
+        }
+    } else {
+        let payload = base58check_decode(address).ok_or_else(|| invalid("invalid base58check"))?;
+        match payload.split_first() {
+            Some((version, hash)) if P2PKH_VERSIONS.contains(version) => (false, hash.to_vec()),
+            _ => return Err(invalid("only P2PKH and P2WPKH addresses are supported")),
+        }
+    };
+    if payload.len() != PUBKEY_HASH_LEN {
+        return Err(invalid("only P2PKH and P2WPKH addresses are supported"));
+    }
+    let mut hash = [0u8; PUBKEY_HASH_LEN];
+    hash.copy_from_slice(&payload);
+    Ok(if segwit {
+        Address::P2wpkh(hash)
+    } else {
+        Address::P2pkh(hash)
+    })
+}
+
+/// Verifies a Bitcoin signed message: a 65-byte compact signature whose header byte
+/// (27 + recovery id, +4 for compressed keys, BIP-137 segwit headers 35-42) is followed by
+/// `r || s`.
+///
+/// The recovered public key must hash to `address`. P2WPKH addresses require a compressed key.
+/// The address type is taken from the address, so signatures of segwit addresses with a
+/// compressed P2PKH header (as created by Electrum) are accepted.
+pub fn verify_message(
+    message: &[u8],
+    signature: &[u8],
+    address: &str,
+) -> Result<bool, ContractError> {
+    let address = decode_address(address)?;
+    if signature.len() != RECOVERABLE_SIGNATURE_LEN {
+        return Err(ContractError::InvalidSignatureEncoding {
+            msg: format!(
+                "Signature must be {} bytes long, got {}",
+                RECOVERABLE_SIGNATURE_LEN,
+                signature.len()
+            ),
+        });
+    }
+    let header = signature[0];
+    if !(27..=42).contains(&header) {
+        return Err(ContractError::InvalidSignatureEncoding {
+            msg: format!(
+                "Invalid signature header {}. Supported values: 27 to 42.",
+                header
+            ),
+        });
+    }
+    let compressed = header >= 31;
+    let recovery_param = (header - 27) % 4;
+
+    let public_key =
+        secp256k1_recover_pubkey(&message_hash(message), &signature[1..], recovery_param)?;
+    let public_key = if compressed {
+        compress_pubkey(&public_key)?
+    } else {
+        public_key
+    };
+    Ok(match address {
+        Address::P2pkh(hash) => hash160(&public_key) == hash,
+        Address::P2wpkh(hash) => compressed && hash160(&public_key) == hash,
+    })
+}
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(&Sha256::digest(data)).into()
+}
+
+/// Bitcoin's variable length integer encoding
+fn varint(n: u64) -> Vec<u8> {
+    match n {
+        0..=0xfc => vec![n as u8],
+        0xfd..=0xffff => [&[0xfd], &(n as u16).to_le_bytes()[..]].concat(),
+        0x1_0000..=0xffff_ffff => [&[0xfe], &(n as u32).to_le_bytes()[..]].concat(),
+        _ => [&[0xff], &n.to_le_bytes()[..]].concat(),
+    }
+}
+
+/// Decodes a base58 string and checks its 4-byte double SHA-256 checksum.
+/// Returns the payload without checksum.
+fn base58check_decode(input: &str) -> Option<Vec<u8>> {
+    // big-endian digits in base 256
+    let mut bytes: Vec<u8> = vec![];
+    for c in input.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    let leading_zeros = input.bytes().take_while(|&c| c == b'1').count();
+    let mut decoded = vec![0u8; leading_zeros];
+    decoded.extend(bytes);
+
+    if decoded.len() < 4 {
+        return None;
+    }
+    let (payload, checksum) = decoded.split_at(decoded.len() - 4);
+    if double_sha256(payload)[..4] != *checksum {
+        return None;
+    }
+    Some(payload.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bech32::ToBase32;
+    use hex_literal::hex;
+
+    #[test]
+    fn varint_works() {
+        assert_eq!(varint(0), vec![0x00]);
+        assert_eq!(varint(0xfc), vec![0xfc]);
+        assert_eq!(varint(0xfd), vec![0xfd, 0xfd, 0x00]);
+        assert_eq!(varint(0x1_0000), vec![0xfe, 0x00, 0x00, 0x01, 0x00]);
+        assert_eq!(
+            varint(0x1_0000_0000),
+            vec![0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn decode_address_works() {
+        // Genesis block coinbase address
+        assert_eq!(
+            decode_address("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap(),
+            Address::P2pkh(hex!("62e907b15cbf27d5425399ebf6f0fb50ebb88f18"))
+        );
+        // BIP-173 test vector
+        assert_eq!(
+            decode_address("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4").unwrap(),
+            Address::P2wpkh(hex!("751e76e8199196d454941c45d1b3a323f1433bd6"))
+        );
+        assert_eq!(
+            decode_address("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").unwrap(),
+            Address::P2wpkh(hex!("751e76e8199196d454941c45d1b3a323f1433bd6"))
+        );
+    }
+
+    #[test]
+    fn decode_address_errors() {
+        let unsupported = [
+            // P2SH
+            "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+            // P2WSH
+            "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+        ];
+        for address in unsupported.iter() {
+            match decode_address(address).unwrap_err() {
+                ContractError::InvalidAddress { msg } => assert_eq!(
+                    msg,
+                    format!(
+                        "Invalid Bitcoin address {}: only P2PKH and P2WPKH addresses are supported",
+                        address
+                    )
+                ),
+                err => panic!("Unexpected error: {:?}", err),
+            }
+        }
+        // checksum mismatch
+        assert!(decode_address("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb").is_err());
+        assert!(decode_address("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5").is_err());
+        // not base58
+        assert!(decode_address("0A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").is_err());
+
+        // a P2WPKH program with a human readable part that only starts like a network's
+        let mut data = vec![bech32::u5::try_from_u8(0).unwrap()];
+        data.extend(hex!("751e76e8199196d454941c45d1b3a323f1433bd6").to_base32());
+        for hrp in ["bc1qw508", "tb1q", "bcrt1q"].iter() {
+            let address = bech32::encode(hrp, &data).unwrap();
+            match decode_address(&address).unwrap_err() {
+                ContractError::InvalidAddress { msg } => assert_eq!(
+                    msg,
+                    format!(
+                        "Invalid Bitcoin address {}: unknown network {}",
+                        address, hrp
+                    )
+                ),
+                err => panic!("Unexpected error: {:?}", err),
+            }
+        }
+    }
+}
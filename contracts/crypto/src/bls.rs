@@ -0,0 +1,161 @@
+use bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
+use bls12_381::{
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Gt,
+};
+use sha2::Sha256;
+use std::collections::HashSet;
+use std::convert::TryInto;
+
+use crate::errors::ContractError;
+
+/// Length of a compressed G1 point
+pub const G1_LEN: usize = 48;
+
+/// Length of a compressed G2 point
+pub const G2_LEN: usize = 96;
+
+/// Default domain separation tag when public keys are in G1 and signatures in G2
+/// (minimal-pubkey-size, e.g. Ethereum consensus).
+pub const DST_G2_SIGNATURES: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Default domain separation tag when public keys are in G2 and signatures in G1
+/// (minimal-signature-size).
+pub const DST_G1_SIGNATURES: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Verifies a BLS12-381 signature, possibly aggregated, against one or more public keys.
+///
+/// The group convention follows the signature length: a 96 byte signature (G2) needs 48 byte
+/// public keys (G1), a 48 byte signature (G1) needs 96 byte public keys (G2).
+/// - One message: the signature is verified against the aggregate of all public keys
+///   (same-message aggregation; requires proof of possession of the keys).
+/// - One message per public key: the signature is verified as aggregate of one signature per
+///   key and message. The messages must be distinct.
+///
+/// `dst` defaults to the proof of possession ciphersuite of the convention in use.
+pub fn bls_verify(
+    messages: &[&[u8]],
+    signature: &[u8],
+    public_keys: &[&[u8]],
+    dst: Option<&[u8]>,
+) -> Result<bool, ContractError> {
+    if public_keys.is_empty() {
+        return Err(ContractError::InvalidInput {
+            msg: "At least one public key is required".to_string(),
+        });
+    }
+    if messages.len() != 1 && messages.len() != public_keys.len() {
+        return Err(ContractError::InvalidInput {
+            msg: format!(
+                "Expected one message, or one message per public key ({}), got {}",
+                public_keys.len(),
+                messages.len()
+            ),
+        });
+    }
+    if messages.len() > 1 {
+        let mut distinct = HashSet::new();
+        if !messages.iter().all(|m| distinct.insert(*m)) {
+            return Err(ContractError::InvalidInput {
+                msg: "Aggregated messages must be distinct".to_string(),
+            });
+        }
+    }
+
+    match signature.len() {
+        G2_LEN => verify_g2_signature(messages, signature, public_keys, dst),
+        G1_LEN => verify_g1_signature(messages, signature, public_keys, dst),
+        _ => Err(ContractError::InvalidSignatureFormat),
+    }
+}
+
+/// Public keys in G1, signature in G2: `e(-g1, sig) * Π e(pk_i, H(m_i)) == 1`
+fn verify_g2_signature(
+    messages: &[&[u8]],
+    signature: &[u8],
+    public_keys: &[&[u8]],
+    dst: Option<&[u8]>,
+) -> Result<bool, ContractError> {
+    let dst = dst.unwrap_or(DST_G2_SIGNATURES);
+    let signature = g2_from_bytes(signature).ok_or(ContractError::InvalidSignatureFormat)?;
+    let public_keys = public_keys
+        .iter()
+        .map(|pk| {
+            g1_from_bytes(pk)
+                .filter(|pk: &G1Affine| !bool::from(pk.is_identity()))
+                .ok_or(ContractError::InvalidPubkeyFormat)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (public_keys, hashes): (Vec<G1Affine>, Vec<G2Prepared>) = if messages.len() == 1 {
+        let aggregate: G1Projective = public_keys.iter().map(G1Projective::from).sum();
+        (vec![aggregate.into()], vec![hash_to_g2(messages[0], dst)])
+    } else {
+        let hashes = messages.iter().map(|m| hash_to_g2(m, dst)).collect();
+        (public_keys, hashes)
+    };
+
+    let generator = -G1Affine::generator();
+    let signature = G2Prepared::from(signature);
+    let mut terms = vec![(&generator, &signature)];
+    terms.extend(public_keys.iter().zip(hashes.iter()));
+    Ok(multi_miller_loop(&terms).final_exponentiation() == Gt::identity())
+}
+
+/// Public keys in G2, signature in G1: `e(sig, -g2) * Π e(H(m_i), pk_i) == 1`
+fn verify_g1_signature(
+    messages: &[&[u8]],
+    signature: &[u8],
+    public_keys: &[&[u8]],
+    dst: Option<&[u8]>,
+) -> Result<bool, ContractError> {
+    let dst = dst.unwrap_or(DST_G1_SIGNATURES);
+    let signature = g1_from_bytes(signature).ok_or(ContractError::InvalidSignatureFormat)?;
+    let public_keys = public_keys
+        .iter()
+        .map(|pk| {
+            g2_from_bytes(pk)
+                .filter(|pk: &G2Affine| !bool::from(pk.is_identity()))
+                .ok_or(ContractError::InvalidPubkeyFormat)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (public_keys, hashes): (Vec<G2Prepared>, Vec<G1Affine>) = if messages.len() == 1 {
+        let aggregate: G2Projective = public_keys.iter().map(G2Projective::from).sum();
+        (
+            vec![G2Affine::from(aggregate).into()],
+            vec![hash_to_g1(messages[0], dst)],
+        )
+    } else {
+        let public_keys = public_keys.into_iter().map(G2Prepared::from).collect();
+        let hashes = messages.iter().map(|m| hash_to_g1(m, dst)).collect();
+        (public_keys, hashes)
+    };
+
+    let generator = G2Prepared::from(-G2Affine::generator());
+    let mut terms = vec![(&signature, &generator)];
+    terms.extend(hashes.iter().zip(public_keys.iter()));
+    Ok(multi_miller_loop(&terms).final_exponentiation() == Gt::identity())
+}
+
+/// Decodes a compressed G1 point, checking that it is in the prime order subgroup
+fn g1_from_bytes(bytes: &[u8]) -> Option<G1Affine> {
+    let bytes: &[u8; G1_LEN] = bytes.try_into().ok()?;
+    G1Affine::from_compressed(bytes).into()
+}
+
+/// Decodes a compressed G2 point, checking that it is in the prime order subgroup
+fn g2_from_bytes(bytes: &[u8]) -> Option<G2Affine> {
+    let bytes: &[u8; G2_LEN] = bytes.try_into().ok()?;
+    G2Affine::from_compressed(bytes).into()
+}
+
+/// Hashes a message to G2 (`BLS12381G2_XMD:SHA-256_SSWU_RO_`)
+pub fn hash_to_g2(message: &[u8], dst: &[u8]) -> G2Prepared {
+    let point = <G2Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(message, dst);
+    G2Affine::from(point).into()
+}
+
+/// Hashes a message to G1 (`BLS12381G1_XMD:SHA-256_SSWU_RO_`)
+pub fn hash_to_g1(message: &[u8], dst: &[u8]) -> G1Affine {
+    <G1Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(message, dst).into()
+}
@@ -0,0 +1,156 @@
+use axelar_gateway_contracts::crypto::{HashMode, KeyEncoding, SchemeCapabilities};
+use cosmwasm_crypto::{ECDSA_SIGNATURE_LEN, EDDSA_PUBKEY_LEN};
+
+use crate::bitcoin::PUBKEY_HASH_LEN;
+use crate::bls::G2_LEN;
+use crate::ethereum::ADDRESS_LEN;
+use crate::schnorr::{SCHNORR_SIGNATURE_LEN, XONLY_PUBKEY_LEN};
+use crate::secp256k1::{RECOVERABLE_SIGNATURE_LEN, UNCOMPRESSED_PUBKEY_LEN};
+
+/// Maximum length of an ASN.1 DER encoded P-256 signature
+const DER_SIGNATURE_MAX_LEN: usize = 72;
+
+/// Length of an ed25519 signature
+const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Compressed and uncompressed SEC1 public keys
+const SEC1: &[KeyEncoding] = &[KeyEncoding::Sec1Compressed, KeyEncoding::Sec1Uncompressed];
+
+/// Hash modes of secp256k1 verification, see `HashFunction`
+const SECP256K1_HASH_MODES: &[HashMode] =
+    &[HashMode::Sha256, HashMode::Keccak256, HashMode::Prehashed];
+
+/// Capabilities of the verification schemes, in the order they are listed.
+///
+/// Schemes are named after what is verified rather than after the queries: `secp256k1` covers
+/// `VerifyCosmosSignature` and `VerifySecp256k1Signature`, and `ed25519` and `ed25519_batch`
+/// are the Tendermint queries `VerifyTendermintSignature` and `VerifyTendermintBatch`.
+pub fn verification_schemes() -> Vec<SchemeCapabilities> {
+    vec![
+        scheme(
+            "secp256k1",
+            SEC1,
+            SECP256K1_HASH_MODES,
+            false,
+            ECDSA_SIGNATURE_LEN,
+            UNCOMPRESSED_PUBKEY_LEN,
+        ),
+        scheme(
+            "secp256k1_batch",
+            SEC1,
+            SECP256K1_HASH_MODES,
+            true,
+            ECDSA_SIGNATURE_LEN,
+            UNCOMPRESSED_PUBKEY_LEN,
+        ),
+        scheme(
+            "adr036",
+            SEC1,
+            &[HashMode::Structured],
+            false,
+            ECDSA_SIGNATURE_LEN,
+            UNCOMPRESSED_PUBKEY_LEN,
+        ),
+        scheme(
+            "secp256r1",
+            SEC1,
+            &[HashMode::Sha256],
+            false,
+            ECDSA_SIGNATURE_LEN,
+            UNCOMPRESSED_PUBKEY_LEN,
+        ),
+        scheme(
+            "webauthn",
+            SEC1,
+            &[HashMode::Structured],
+            false,
+            DER_SIGNATURE_MAX_LEN,
+            UNCOMPRESSED_PUBKEY_LEN,
+        ),
+        scheme(
+            "ethereum_text",
+            &[KeyEncoding::EthereumAddress],
+            &[HashMode::Structured],
+            false,
+            RECOVERABLE_SIGNATURE_LEN,
+            ADDRESS_LEN,
+        ),
+        // `r || s`, `v` is passed separately
+        scheme(
+            "ethereum_transaction",
+            &[KeyEncoding::EthereumAddress],
+            &[HashMode::Structured],
+            false,
+            ECDSA_SIGNATURE_LEN,
+            ADDRESS_LEN,
+        ),
+        scheme(
+            "eip712",
+            &[KeyEncoding::EthereumAddress],
+            &[HashMode::Structured],
+            false,
+            RECOVERABLE_SIGNATURE_LEN,
+            ADDRESS_LEN,
+        ),
+        scheme(
+            "bitcoin_message",
+            &[KeyEncoding::BitcoinAddress],
+            &[HashMode::Structured],
+            false,
+            RECOVERABLE_SIGNATURE_LEN,
+            PUBKEY_HASH_LEN,
+        ),
+        scheme(
+            "bip340",
+            &[KeyEncoding::XOnly],
+            &[HashMode::Native],
+            false,
+            SCHNORR_SIGNATURE_LEN,
+            XONLY_PUBKEY_LEN,
+        ),
+        scheme(
+            "bls12_381",
+            &[KeyEncoding::BlsG1, KeyEncoding::BlsG2],
+            &[HashMode::Native],
+            true,
+            G2_LEN,
+            G2_LEN,
+        ),
+        scheme(
+            "ed25519",
+            &[KeyEncoding::Ed25519],
+            &[HashMode::Native],
+            false,
+            ED25519_SIGNATURE_LEN,
+            EDDSA_PUBKEY_LEN,
+        ),
+        scheme(
+            "ed25519_batch",
+            &[KeyEncoding::Ed25519],
+            &[HashMode::Native],
+            true,
+            ED25519_SIGNATURE_LEN,
+            EDDSA_PUBKEY_LEN,
+        ),
+    ]
+}
+
+fn scheme(
+    name: &str,
+    key_encodings: &[KeyEncoding],
+    hash_modes: &[HashMode],
+    batch: bool,
+    max_signature_len: usize,
+    max_public_key_len: usize,
+) -> SchemeCapabilities {
+    SchemeCapabilities {
+        scheme: name.to_string(),
+        key_encodings: key_encodings.to_vec(),
+        hash_modes: hash_modes.to_vec(),
+        batch,
+        max_signature_len: max_signature_len as u32,
+        max_public_key_len: max_public_key_len as u32,
+        // batches are only limited by gas
+        max_batch_size: None,
+    }
+}
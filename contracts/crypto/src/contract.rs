@@ -1,18 +1,43 @@
 use cosmwasm_std::{
-    to_binary, Api, Binary, CanonicalAddr, Env, Extern, HandleResponse, InitResponse, Querier,
-    StdError, StdResult, Storage, Uint128,
+    log, to_binary, Api, Binary, CanonicalAddr, Env, Extern, HandleResponse, HumanAddr,
+    InitResponse, Querier, StdError, StdResult, Storage, Uint128,
 };
 use sha2::{Digest, Sha256};
 use sha3::Keccak256;
 use std::ops::Deref;
 
-use cosmwasm_crypto::{secp256k1_recover_pubkey, secp256k1_verify};
+use cosmwasm_crypto::{ed25519_verify, secp256k1_verify};
+use cw2::{get_contract_version, set_contract_version};
 
-use axelar_gateway_contracts::{
-    crypto::{InitMsg, ListVerificationsResponse, QueryMsg, VerifyResponse},
-    gateway::HandleMsg,
+use crate::adr036;
+use crate::batch::batch_entries;
+use crate::bitcoin;
+use crate::bls::bls_verify;
+use crate::capabilities::verification_schemes;
+use crate::cosmos::address_from_pubkey;
+use crate::ed25519::batch_verify;
+use crate::eip712;
+use crate::errors::{verification_error, ContractError};
+use crate::ethereum;
+use crate::hash;
+use crate::merkle;
+use crate::schnorr::schnorr_verify;
+use crate::secp256k1::{compress_pubkey, decompress_pubkey, parse_pubkey, recover_pubkey};
+use crate::secp256r1::secp256r1_verify;
+use crate::state::{is_digest_consumed, store_consumed_digest};
+use crate::webauthn::{self, Assertion, Expected};
+
+use axelar_gateway_contracts::crypto::{
+    AddressResponse, CapabilitiesResponse, DigestConsumedResponse, Eip712Domain,
+    Eip712SignerResponse, Eip712Type, EntryFailure, EthereumLog, EthereumReceipt,
+    EthereumReceiptResponse, HandleMsg, HashAlgorithm, HashBatchResponse, HashFunction,
+    HashResponse, InitMsg, ListVerificationsResponse, PubkeyResponse, QueryMsg,
+    RecoverCosmosAddressResponse, RecoverPubkeyResponse, SignerSignature, ValidatePubkeyResponse,
+    VerifyBatchResponse, VerifyResponse, VerifySecp256k1BatchResponse, VerifyThresholdResponse,
+    WeightedSigner,
 };
 
+pub const CONTRACT_NAME: &str = "crates.io:axelar-crypto";
 pub const VERSION: &str = "crypto-verify-v2";
 
 pub fn init<S: Storage, A: Api, Q: Querier>(
@@ -20,6 +45,7 @@ pub fn init<S: Storage, A: Api, Q: Querier>(
     _env: Env,
     _msg: InitMsg,
 ) -> StdResult<InitResponse> {
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, VERSION)?;
     Ok(InitResponse::default())
 }
 
@@ -28,7 +54,46 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
     env: Env,
     msg: HandleMsg,
 ) -> StdResult<HandleResponse> {
-    Err(StdError::not_found("no handlers exist"))
+    match msg {
+        HandleMsg::ConsumeSignature {
+            digest,
+            signature,
+            pubkey,
+        } => handle_consume_signature(
+            deps,
+            env,
+            digest.as_slice(),
+            signature.as_slice(),
+            pubkey.as_slice(),
+        ),
+    }
+}
+
+/// Verifies the signature of `digest` and records the digest in the caller's namespace, so that
+/// the same signed message cannot be consumed twice by the same contract.
+pub fn handle_consume_signature<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    digest: &[u8],
+    signature: &[u8],
+    pubkey: &[u8],
+) -> StdResult<HandleResponse> {
+    let caller = deps.api.canonical_address(&env.message.sender)?;
+    if is_digest_consumed(&deps.storage, &caller, digest) {
+        return Err(StdError::generic_err("Signature already consumed"));
+    }
+    if !secp256k1_verify(digest, signature, pubkey).map_err(verification_error)? {
+        return Err(StdError::unauthorized());
+    }
+    store_consumed_digest(&mut deps.storage, &caller, digest);
+
+    let mut res = HandleResponse::default();
+    res.log = vec![
+        log("action", "consume_signature"),
+        log("caller", env.message.sender),
+        log("digest", hex::encode(digest)),
+    ];
+    Ok(res)
 }
 
 pub fn query<S: Storage, A: Api, Q: Querier>(
@@ -46,163 +111,4290 @@ pub fn query<S: Storage, A: Api, Q: Querier>(
             signature.as_slice(),
             public_key.as_slice(),
         )?),
-        QueryMsg::RecoverCosmosAddress { .. } => Ok(Binary::default()),
+        QueryMsg::VerifyAdr036Signature {
+            signer,
+            data,
+            signature,
+            public_key,
+        } => to_binary(&query_verify_adr036(
+            deps,
+            &signer,
+            data.as_slice(),
+            signature.as_slice(),
+            public_key.as_slice(),
+        )?),
+        QueryMsg::VerifySecp256k1Signature {
+            message,
+            signature,
+            public_key,
+            hash_function,
+        } => to_binary(&query_verify_secp256k1(
+            deps,
+            message.as_slice(),
+            signature.as_slice(),
+            public_key.as_slice(),
+            hash_function,
+        )?),
+        QueryMsg::VerifySecp256k1Batch {
+            messages,
+            signatures,
+            public_keys,
+            hash_function,
+        } => {
+            let messages: Vec<&[u8]> = messages.iter().map(|m| m.as_slice()).collect();
+            let signatures: Vec<&[u8]> = signatures.iter().map(|m| m.as_slice()).collect();
+            let public_keys: Vec<&[u8]> = public_keys.iter().map(|m| m.as_slice()).collect();
+            to_binary(&query_verify_secp256k1_batch(
+                deps,
+                &messages,
+                &signatures,
+                &public_keys,
+                hash_function,
+            )?)
+        }
+        QueryMsg::VerifyThreshold {
+            message,
+            hash_function,
+            signers,
+            threshold,
+            signatures,
+        } => to_binary(&query_verify_threshold(
+            deps,
+            message.as_slice(),
+            hash_function,
+            &signers,
+            threshold,
+            &signatures,
+        )?),
+        QueryMsg::RecoverPubkey {
+            message,
+            signature,
+            hash_function,
+        } => to_binary(&query_recover_pubkey(
+            deps,
+            message.as_slice(),
+            signature.as_slice(),
+            hash_function,
+        )?),
+        QueryMsg::RecoverCosmosAddress {
+            message,
+            signature,
+            hash_function,
+            prefix,
+        } => to_binary(&query_recover_cosmos_address(
+            deps,
+            message.as_slice(),
+            signature.as_slice(),
+            hash_function,
+            &prefix,
+        )?),
+        QueryMsg::VerifySecp256r1Signature {
+            message,
+            signature,
+            public_key,
+        } => to_binary(&query_verify_secp256r1(
+            deps,
+            message.as_slice(),
+            signature.as_slice(),
+            public_key.as_slice(),
+        )?),
+        QueryMsg::VerifyWebauthnAssertion {
+            authenticator_data,
+            client_data_json,
+            signature,
+            public_key,
+            challenge,
+            rp_id,
+            origin,
+            require_user_verification,
+        } => to_binary(&query_verify_webauthn(
+            deps,
+            &Assertion {
+                authenticator_data: authenticator_data.as_slice(),
+                client_data_json: client_data_json.as_slice(),
+                signature: signature.as_slice(),
+            },
+            public_key.as_slice(),
+            &Expected {
+                challenge: challenge.as_slice(),
+                rp_id: &rp_id,
+                origin: origin.as_deref(),
+                require_user_verification,
+            },
+        )?),
+        QueryMsg::VerifySchnorrSignature {
+            message,
+            signature,
+            public_key,
+        } => to_binary(&query_verify_schnorr(
+            deps,
+            message.as_slice(),
+            signature.as_slice(),
+            public_key.as_slice(),
+        )?),
+        QueryMsg::VerifyBlsSignature {
+            message,
+            signature,
+            public_key,
+            dst,
+        } => to_binary(&query_verify_bls(
+            deps,
+            &[message.as_slice()],
+            signature.as_slice(),
+            &[public_key.as_slice()],
+            dst.as_ref().map(|dst| dst.as_slice()),
+        )?),
+        QueryMsg::VerifyBlsAggregate {
+            messages,
+            signature,
+            public_keys,
+            dst,
+        } => {
+            let messages: Vec<&[u8]> = messages.iter().map(|m| m.as_slice()).collect();
+            let public_keys: Vec<&[u8]> = public_keys.iter().map(|k| k.as_slice()).collect();
+            to_binary(&query_verify_bls(
+                deps,
+                &messages,
+                signature.as_slice(),
+                &public_keys,
+                dst.as_ref().map(|dst| dst.as_slice()),
+            )?)
+        }
+        QueryMsg::VerifyTendermintSignature {
+            message,
+            signature,
+            public_key,
+        } => to_binary(&query_verify_tendermint(
+            deps,
+            message.as_slice(),
+            signature.as_slice(),
+            public_key.as_slice(),
+        )?),
+        QueryMsg::VerifyTendermintBatch {
+            messages,
+            signatures,
+            public_keys,
+        } => {
+            let messages: Vec<&[u8]> = messages.iter().map(|m| m.as_slice()).collect();
+            let signatures: Vec<&[u8]> = signatures.iter().map(|m| m.as_slice()).collect();
+            let public_keys: Vec<&[u8]> = public_keys.iter().map(|m| m.as_slice()).collect();
+            to_binary(&query_verify_tendermint_batch(
+                deps,
+                &messages,
+                &signatures,
+                &public_keys,
+            )?)
+        }
+        QueryMsg::VerifyEthereumText {
+            message,
+            signature,
+            signer_address,
+        } => to_binary(&query_verify_ethereum_text(
+            deps,
+            &message,
+            signature.as_slice(),
+            &signer_address,
+        )?),
+        QueryMsg::VerifyBitcoinMessage {
+            message,
+            signature,
+            address,
+        } => to_binary(&query_verify_bitcoin_message(
+            deps,
+            &message,
+            signature.as_slice(),
+            &address,
+        )?),
+        QueryMsg::VerifyEthereumTransaction {
+            from,
+            to,
+            nonce,
+            gas_limit,
+            gas_price,
+            value,
+            data,
+            chain_id,
+            r,
+            s,
+            v,
+        } => {
+            let to = if to.is_empty() {
+                vec![]
+            } else {
+                match ethereum::decode_address(&to) {
+                    Ok(to) => to.to_vec(),
+                    Err(err) => return to_binary(&verify_response(Err(err))),
+                }
+            };
+            let tx = ethereum::LegacyTransaction {
+                nonce,
+                gas_price: gas_price.u128(),
+                gas_limit: gas_limit.u128(),
+                to: &to,
+                value: value.u128(),
+                data: data.as_slice(),
+                chain_id,
+            };
+            to_binary(&query_verify_ethereum_transaction(
+                deps,
+                &from,
+                &tx,
+                r.as_slice(),
+                s.as_slice(),
+                v,
+            )?)
+        }
+        QueryMsg::VerifyEthereumReceiptProof {
+            receipts_root,
+            tx_index,
+            proof,
+        } => {
+            let proof: Vec<&[u8]> = proof.iter().map(|node| node.as_slice()).collect();
+            to_binary(&query_verify_ethereum_receipt_proof(
+                deps,
+                receipts_root.as_slice(),
+                tx_index,
+                &proof,
+            )?)
+        }
+        QueryMsg::VerifyTendermintMerkleProof {
+            leaf,
+            index,
+            total,
+            aunts,
+            root,
+        } => {
+            let aunts: Vec<&[u8]> = aunts.iter().map(|a| a.as_slice()).collect();
+            to_binary(&query_verify_tendermint_merkle_proof(
+                deps,
+                leaf.as_slice(),
+                index,
+                total,
+                &aunts,
+                root.as_slice(),
+            )?)
+        }
+        QueryMsg::VerifyKeccakMerkleProof { leaf, proof, root } => {
+            let proof: Vec<&[u8]> = proof.iter().map(|p| p.as_slice()).collect();
+            to_binary(&query_verify_keccak_merkle_proof(
+                deps,
+                leaf.as_slice(),
+                &proof,
+                root.as_slice(),
+            )?)
+        }
+        QueryMsg::CompressPubkey { public_key } => {
+            to_binary(&query_compress_pubkey(deps, public_key.as_slice())?)
+        }
+        QueryMsg::DecompressPubkey { public_key } => {
+            to_binary(&query_decompress_pubkey(deps, public_key.as_slice())?)
+        }
+        QueryMsg::DeriveCosmosAddress { public_key, prefix } => to_binary(
+            &query_derive_cosmos_address(deps, public_key.as_slice(), &prefix)?,
+        ),
+        QueryMsg::DeriveEthereumAddress { public_key } => {
+            to_binary(&query_derive_ethereum_address(deps, public_key.as_slice())?)
+        }
+        QueryMsg::ValidatePubkey { public_key } => {
+            to_binary(&query_validate_pubkey(deps, public_key.as_slice())?)
+        }
+        QueryMsg::VerifyEip712Signature {
+            domain,
+            types,
+            primary_type,
+            message,
+            signature,
+            signer_address,
+        } => to_binary(&query_verify_eip712(
+            deps,
+            &domain,
+            &types,
+            &primary_type,
+            &message,
+            signature.as_slice(),
+            &signer_address,
+        )?),
+        QueryMsg::RecoverEip712Signer {
+            domain,
+            types,
+            primary_type,
+            message,
+            signature,
+        } => to_binary(&query_recover_eip712_signer(
+            deps,
+            &domain,
+            &types,
+            &primary_type,
+            &message,
+            signature.as_slice(),
+        )?),
+        QueryMsg::Hash { algorithm, data } => {
+            to_binary(&query_hash(deps, algorithm, data.as_slice())?)
+        }
+        QueryMsg::HashBatch { algorithm, data } => {
+            let data: Vec<&[u8]> = data.iter().map(|item| item.as_slice()).collect();
+            to_binary(&query_hash_batch(deps, algorithm, &data)?)
+        }
+        QueryMsg::IsDigestConsumed { caller, digest } => {
+            to_binary(&query_is_digest_consumed(deps, &caller, digest.as_slice())?)
+        }
         QueryMsg::ListVerificationSchemes {} => to_binary(&query_list_verifications(deps)?),
+        QueryMsg::Capabilities {} => to_binary(&query_capabilities(deps)?),
     }
 }
 
 pub fn query_verify_cosmos<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> StdResult<VerifyResponse> {
+    query_verify_secp256k1(deps, message, signature, public_key, HashFunction::Sha256)
+}
+
+pub fn query_verify_adr036<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    signer: &str,
+    data: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> StdResult<VerifyResponse> {
+    Ok(verify_response(adr036::verify(
+        signer, data, signature, public_key,
+    )))
+}
+
+pub fn query_verify_secp256k1<S: Storage, A: Api, Q: Querier>(
     _deps: &Extern<S, A, Q>,
     message: &[u8],
     signature: &[u8],
     public_key: &[u8],
+    hash_function: HashFunction,
 ) -> StdResult<VerifyResponse> {
     // Hashing
-    let hash = Sha256::digest(message);
+    let hash = hash_message(message, hash_function);
 
     // Verification
-    let result = secp256k1_verify(hash.as_ref(), signature, public_key);
+    Ok(verify_response(
+        secp256k1_verify(hash.as_ref(), signature, public_key).map_err(ContractError::from),
+    ))
+}
+
+/// Builds a verification response. Malformed inputs are reported as failure reason
+/// instead of a query error.
+fn verify_response(result: Result<bool, ContractError>) -> VerifyResponse {
     match result {
-        Ok(verifies) => Ok(VerifyResponse { verifies }),
-        Err(err) => Err(StdError::generic_err(err.to_string())),
+        Ok(verifies) => VerifyResponse {
+            verifies,
+            failure_reason: None,
+        },
+        Err(err) => VerifyResponse {
+            verifies: false,
+            failure_reason: Some(err.failure_reason()),
+        },
     }
 }
 
-pub fn query_list_verifications<S: Storage, A: Api, Q: Querier>(
-    deps: &Extern<S, A, Q>,
-) -> StdResult<ListVerificationsResponse> {
-    let verification_schemes: Vec<_> = list_verifications(deps);
-    Ok(ListVerificationsResponse {
-        verification_schemes,
+pub fn query_verify_secp256k1_batch<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    messages: &[&[u8]],
+    signatures: &[&[u8]],
+    public_keys: &[&[u8]],
+    hash_function: HashFunction,
+) -> StdResult<VerifySecp256k1BatchResponse> {
+    let entries = match batch_entries(messages, signatures, public_keys) {
+        Ok(entries) => entries,
+        Err(err) => {
+            return Ok(VerifySecp256k1BatchResponse {
+                verifies: false,
+                results: vec![],
+                failure_reason: Some(err.failure_reason()),
+                entry_failures: vec![],
+            })
+        }
+    };
+    // A malformed signature or public key only fails its own entry
+    let mut results = Vec::with_capacity(entries.len());
+    let mut entry_failures = vec![];
+    for (index, (message, signature, public_key)) in entries.into_iter().enumerate() {
+        let hash = hash_message(message, hash_function);
+        match secp256k1_verify(&hash, signature, public_key) {
+            Ok(verifies) => results.push(verifies),
+            Err(err) => {
+                results.push(false);
+                entry_failures.push(entry_failure(index, err.into()));
+            }
+        }
+    }
+    Ok(VerifySecp256k1BatchResponse {
+        verifies: results.iter().all(|verifies| *verifies),
+        results,
+        failure_reason: None,
+        entry_failures,
     })
 }
 
-pub(crate) fn list_verifications<S: Storage, A: Api, Q: Querier>(
-    _deps: &Extern<S, A, Q>,
-) -> Vec<String> {
-    vec!["secp256k1".into()]
+/// Reports why a malformed entry of a batch or threshold query failed
+fn entry_failure(index: usize, err: ContractError) -> EntryFailure {
+    EntryFailure {
+        index: index as u32,
+        failure_reason: err.failure_reason(),
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use cosmwasm_crypto::CryptoError;
-    use cosmwasm_std::{
-        from_binary,
-        testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage},
-        HumanAddr,
-    };
-    use cosmwasm_std::{from_slice, Binary, StdError};
-    use hex_literal::hex;
-    use k256::{
-        ecdsa::{signature::Signer, signature::Verifier, Signature, SigningKey, VerifyingKey},
-        elliptic_curve::sec1::ToEncodedPoint,
-        CompressedPoint, EncodedPoint, PublicKey, SecretKey,
-    };
-    use rand_core::OsRng;
-    use sha2::{Digest, Sha256};
-
-    const CREATOR: &str = "creator";
-
-    const SECP256K1_MESSAGE_HEX: &str = "5c868fedb8026979ebd26f1ba07c27eedf4ff6d10443505a96ecaf21ba8c4f0937b3cd23ffdc3dd429d4cd1905fb8dbcceeff1350020e18b58d2ba70887baa3a9b783ad30d3fbf210331cdd7df8d77defa398cdacdfc2e359c7ba4cae46bb74401deb417f8b912a1aa966aeeba9c39c7dd22479ae2b30719dca2f2206c5eb4b7";
-    const SECP256K1_SIGNATURE_HEX: &str = "207082eb2c3dfa0b454e0906051270ba4074ac93760ba9e7110cd9471475111151eb0dbbc9920e72146fb564f99d039802bf6ef2561446eb126ef364d21ee9c4";
-    const SECP256K1_PUBLIC_KEY_HEX: &str = "04051c1ee2190ecfb174bfe4f90763f2b4ff7517b70a2aec1876ebcfd644c4633fb03f3cfbd94b1f376e34592d9d41ccaf640bb751b00a1fadeb0c01157769eb73";
-
-    #[test]
-    fn comsos_verify_message_batch() {
-        // Signing
-        let signing_key = SigningKey::random(&mut OsRng); // Serialize with `::to_bytes()`
-        let pub_key = VerifyingKey::from(&signing_key); // Serialize with `::to_encoded_point()`
-
-        let msg = InitMsg {};
-        let messages = vec![msg.clone(), msg.clone(), msg.clone()];
-        let digest = Sha256::digest(to_binary(&messages).unwrap().as_slice());
+pub fn query_verify_threshold<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    message: &[u8],
+    hash_function: HashFunction,
+    signers: &[WeightedSigner],
+    threshold: Uint128,
+    signatures: &[SignerSignature],
+) -> StdResult<VerifyThresholdResponse> {
+    if let Err(err) = check_threshold_inputs(signers, threshold, signatures) {
+        return Ok(VerifyThresholdResponse {
+            threshold_met: false,
+            signed_weight: Uint128::zero(),
+            verified_signers: vec![],
+            failure_reason: Some(err.failure_reason()),
+            entry_failures: vec![],
+        });
+    }
 
-        let signature: Signature = signing_key.sign(&digest);
+    let hash = hash_message(message, hash_function);
+    let mut signed_weight: u128 = 0;
+    let mut verified_signers = vec![];
+    let mut entry_failures = vec![];
+    for SignerSignature {
+        signer_index,
+        signature,
+    } in signatures
+    {
+        let signer = &signers[*signer_index as usize];
+        // A malformed signature or key only fails its own signer, not the whole query
+        match secp256k1_verify(&hash, signature.as_slice(), signer.public_key.as_slice()) {
+            Ok(true) => {
+                // Cannot overflow, it is bounded by the total weight
+                signed_weight += signer.weight.u128();
+                verified_signers.push(*signer_index);
+            }
+            Ok(false) => {}
+            Err(err) => entry_failures.push(entry_failure(*signer_index as usize, err.into())),
+        }
+    }
 
-        let verify_msg = QueryMsg::VerifyCosmosSignature {
-            message: Binary::from(digest.as_slice()),
-            signature: Binary::from(signature.as_ref()),
-            public_key: Binary::from(pub_key.to_encoded_point(false).as_bytes()),
-        };
+    Ok(VerifyThresholdResponse {
+        threshold_met: signed_weight >= threshold.u128(),
+        signed_weight: Uint128(signed_weight),
+        verified_signers,
+        failure_reason: None,
+        entry_failures,
+    })
+}
 
-        let deps = setup();
-        let raw = query(&deps, verify_msg).unwrap();
-        let res: VerifyResponse = from_binary(&raw).unwrap();
-        assert_eq!(res, VerifyResponse { verifies: true });
+/// Checks that the threshold is reachable by a valid signer set, and that each signature
+/// references a distinct signer.
+fn check_threshold_inputs(
+    signers: &[WeightedSigner],
+    threshold: Uint128,
+    signatures: &[SignerSignature],
+) -> Result<(), ContractError> {
+    let invalid_input = |msg: String| ContractError::InvalidInput { msg };
+    if threshold.is_zero() {
+        return Err(invalid_input(
+            "Threshold must be greater than zero".to_string(),
+        ));
     }
-
-    fn setup() -> Extern<MockStorage, MockApi, MockQuerier> {
-        let mut deps = mock_dependencies(20, &[]);
-        let msg = InitMsg {};
-        let res = init(&mut deps, mock_env(HumanAddr::from("addr01"), &[]), msg).unwrap();
-        assert_eq!(0, res.messages.len());
-        deps
+    let mut total_weight: u128 = 0;
+    for (index, signer) in signers.iter().enumerate() {
+        if signer.weight.is_zero() {
+            return Err(invalid_input(format!("Signer {} has zero weight", index)));
+        }
+        total_weight = total_weight
+            .checked_add(signer.weight.u128())
+            .ok_or_else(|| invalid_input("Total signer weight overflow".to_string()))?;
+    }
+    if total_weight == 0 {
+        return Err(invalid_input(
+            "Total signer weight must be greater than zero".to_string(),
+        ));
     }
 
-    #[test]
-    fn instantiate_works() {
-        setup();
+    let mut seen = vec![false; signers.len()];
+    for SignerSignature { signer_index, .. } in signatures {
+        match seen.get_mut(*signer_index as usize) {
+            None => {
+                return Err(invalid_input(format!(
+                    "Signer index {} out of range ({} signers)",
+                    signer_index,
+                    signers.len()
+                )))
+            }
+            Some(true) => {
+                return Err(invalid_input(format!(
+                    "Duplicate signature for signer {}",
+                    signer_index
+                )))
+            }
+            Some(seen) => *seen = true,
+        }
     }
+    Ok(())
+}
 
-    #[test]
-    fn cosmos_signature_verify_works() {
-        let deps = setup();
+pub fn query_verify_secp256r1<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> StdResult<VerifyResponse> {
+    Ok(verify_response(secp256r1_verify(
+        message, signature, public_key,
+    )))
+}
 
-        let message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
-        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
-        let public_key = hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap();
+pub fn query_verify_webauthn<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    assertion: &Assertion,
+    public_key: &[u8],
+    expected: &Expected,
+) -> StdResult<VerifyResponse> {
+    Ok(verify_response(webauthn::verify_assertion(
+        assertion, public_key, expected,
+    )))
+}
 
-        let verify_msg = QueryMsg::VerifyCosmosSignature {
-            message: Binary(message),
-            signature: Binary(signature),
-            public_key: Binary(public_key),
-        };
+pub fn query_verify_schnorr<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> StdResult<VerifyResponse> {
+    Ok(verify_response(schnorr_verify(
+        message, signature, public_key,
+    )))
+}
 
-        let raw = query(&deps, verify_msg).unwrap();
-        let res: VerifyResponse = from_binary(&raw).unwrap();
+pub fn query_verify_bls<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    messages: &[&[u8]],
+    signature: &[u8],
+    public_keys: &[&[u8]],
+    dst: Option<&[u8]>,
+) -> StdResult<VerifyResponse> {
+    Ok(verify_response(bls_verify(
+        messages,
+        signature,
+        public_keys,
+        dst,
+    )))
+}
 
-        assert_eq!(res, VerifyResponse { verifies: true });
-    }
+pub fn query_verify_tendermint<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> StdResult<VerifyResponse> {
+    // Verification
+    Ok(verify_response(
+        ed25519_verify(message, signature, public_key).map_err(ContractError::from),
+    ))
+}
 
-    #[test]
-    fn cosmos_signature_verify_fails() {
-        let deps = setup();
+pub fn query_recover_pubkey<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    message: &[u8],
+    signature: &[u8],
+    hash_function: HashFunction,
+) -> StdResult<RecoverPubkeyResponse> {
+    let hash = hash_message(message, hash_function);
+    let public_key = recover_pubkey(&hash, signature)?;
+    Ok(RecoverPubkeyResponse {
+        public_key: Binary(public_key),
+    })
+}
 
-        let mut message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
-        // alter message
-        message[0] ^= 0x01;
-        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
-        let public_key = hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap();
+pub fn query_recover_cosmos_address<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    message: &[u8],
+    signature: &[u8],
+    hash_function: HashFunction,
+    prefix: &str,
+) -> StdResult<RecoverCosmosAddressResponse> {
+    let hash = hash_message(message, hash_function);
+    let public_key = compress_pubkey(&recover_pubkey(&hash, signature)?)?;
+    Ok(RecoverCosmosAddressResponse {
+        address: address_from_pubkey(prefix, &public_key)?,
+        public_key: Binary(public_key),
+    })
+}
 
-        let verify_msg = QueryMsg::VerifyCosmosSignature {
-            message: Binary(message),
-            signature: Binary(signature),
-            public_key: Binary(public_key),
+pub fn query_verify_tendermint_batch<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    messages: &[&[u8]],
+    signatures: &[&[u8]],
+    public_keys: &[&[u8]],
+) -> StdResult<VerifyBatchResponse> {
+    let entries = match batch_entries(messages, signatures, public_keys) {
+        Ok(entries) => entries,
+        Err(err) => {
+            return Ok(VerifyBatchResponse {
+                verifies: false,
+                failed_indices: vec![],
+                failure_reason: Some(err.failure_reason()),
+                entry_failures: vec![],
+            })
+        }
+    };
+    let mut failed_indices = vec![];
+    let mut entry_failures = vec![];
+    for (index, err) in batch_verify(&entries) {
+        failed_indices.push(index as u32);
+        if let Some(err) = err {
+            entry_failures.push(entry_failure(index, err));
+        }
+    }
+    Ok(VerifyBatchResponse {
+        verifies: failed_indices.is_empty(),
+        failed_indices,
+        failure_reason: None,
+        entry_failures,
+    })
+}
+
+pub fn query_verify_ethereum_text<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    message: &str,
+    signature: &[u8],
+    signer_address: &str,
+) -> StdResult<VerifyResponse> {
+    let hash = ethereum::text_message_hash(message.as_bytes());
+    Ok(verify_response(ethereum::verify_signer(
+        &hash,
+        signature,
+        signer_address,
+    )))
+}
+
+pub fn query_verify_bitcoin_message<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    message: &str,
+    signature: &[u8],
+    address: &str,
+) -> StdResult<VerifyResponse> {
+    Ok(verify_response(bitcoin::verify_message(
+        message.as_bytes(),
+        signature,
+        address,
+    )))
+}
+
+pub fn query_verify_ethereum_transaction<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    from: &str,
+    tx: &ethereum::LegacyTransaction,
+    r: &[u8],
+    s: &[u8],
+    v: u64,
+) -> StdResult<VerifyResponse> {
+    Ok(verify_response(tx.verify_sender(from, r, s, v)))
+}
+
+pub fn query_verify_ethereum_receipt_proof<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    receipts_root: &[u8],
+    tx_index: u64,
+    proof: &[&[u8]],
+) -> StdResult<EthereumReceiptResponse> {
+    let receipt = match ethereum::verify_receipt_proof(receipts_root, tx_index, proof) {
+        Ok(receipt) => receipt,
+        Err(err) => {
+            return Ok(EthereumReceiptResponse {
+                verifies: false,
+                failure_reason: Some(err.failure_reason()),
+                receipt: None,
+            })
+        }
+    };
+    Ok(EthereumReceiptResponse {
+        verifies: receipt.is_some(),
+        failure_reason: None,
+        receipt: receipt.map(|receipt| EthereumReceipt {
+            tx_type: receipt.tx_type,
+            success: receipt.success,
+            cumulative_gas_used: Uint128(receipt.cumulative_gas_used.into()),
+            logs: receipt
+                .logs
+                .into_iter()
+                .map(|log| EthereumLog {
+                    address: format!("0x{}", hex::encode(log.address)),
+                    topics: log.topics.into_iter().map(Binary).collect(),
+                    data: Binary(log.data),
+                })
+                .collect(),
+        }),
+    })
+}
+
+pub fn query_verify_tendermint_merkle_proof<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    leaf: &[u8],
+    index: u64,
+    total: u64,
+    aunts: &[&[u8]],
+    root: &[u8],
+) -> StdResult<VerifyResponse> {
+    Ok(verify_response(merkle::verify_rfc6962_proof(
+        leaf, index, total, aunts, root,
+    )))
+}
+
+pub fn query_verify_keccak_merkle_proof<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    leaf: &[u8],
+    proof: &[&[u8]],
+    root: &[u8],
+) -> StdResult<VerifyResponse> {
+    Ok(verify_response(merkle::verify_sorted_keccak_proof(
+        leaf, proof, root,
+    )))
+}
+
+pub fn query_compress_pubkey<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    public_key: &[u8],
+) -> StdResult<PubkeyResponse> {
+    Ok(PubkeyResponse {
+        public_key: Binary(compress_pubkey(public_key)?),
+    })
+}
+
+pub fn query_decompress_pubkey<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    public_key: &[u8],
+) -> StdResult<PubkeyResponse> {
+    Ok(PubkeyResponse {
+        public_key: Binary(decompress_pubkey(public_key)?),
+    })
+}
+
+pub fn query_derive_cosmos_address<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    public_key: &[u8],
+    prefix: &str,
+) -> StdResult<AddressResponse> {
+    Ok(AddressResponse {
+        address: address_from_pubkey(prefix, public_key)?,
+    })
+}
+
+pub fn query_derive_ethereum_address<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    public_key: &[u8],
+) -> StdResult<AddressResponse> {
+    let address = ethereum::address_from_pubkey(&decompress_pubkey(public_key)?)?;
+    Ok(AddressResponse {
+        address: ethereum::checksum_address(&address),
+    })
+}
+
+pub fn query_validate_pubkey<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    public_key: &[u8],
+) -> StdResult<ValidatePubkeyResponse> {
+    Ok(ValidatePubkeyResponse {
+        valid: parse_pubkey(public_key).is_ok(),
+    })
+}
+
+pub fn query_verify_eip712<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    domain: &Eip712Domain,
+    types: &[Eip712Type],
+    primary_type: &str,
+    message: &str,
+    signature: &[u8],
+    signer_address: &str,
+) -> StdResult<VerifyResponse> {
+    let result = eip712::signing_hash(domain, types, primary_type, message)
+        .and_then(|hash| ethereum::verify_signer(&hash, signature, signer_address));
+    Ok(verify_response(result))
+}
+
+pub fn query_recover_eip712_signer<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    domain: &Eip712Domain,
+    types: &[Eip712Type],
+    primary_type: &str,
+    message: &str,
+    signature: &[u8],
+) -> StdResult<Eip712SignerResponse> {
+    let hash = eip712::signing_hash(domain, types, primary_type, message)?;
+    let address = ethereum::recover_address(&hash, signature)?;
+    Ok(Eip712SignerResponse {
+        address: ethereum::checksum_address(&address),
+        signing_hash: Binary(hash.to_vec()),
+    })
+}
+
+pub fn query_hash<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    algorithm: HashAlgorithm,
+    data: &[u8],
+) -> StdResult<HashResponse> {
+    Ok(HashResponse {
+        hash: Binary(hash::digest(algorithm, data)),
+    })
+}
+
+pub fn query_hash_batch<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+    algorithm: HashAlgorithm,
+    data: &[&[u8]],
+) -> StdResult<HashBatchResponse> {
+    Ok(HashBatchResponse {
+        hashes: data
+            .iter()
+            .map(|item| Binary(hash::digest(algorithm, item)))
+            .collect(),
+    })
+}
+
+pub fn query_is_digest_consumed<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    caller: &HumanAddr,
+    digest: &[u8],
+) -> StdResult<DigestConsumedResponse> {
+    let caller = deps.api.canonical_address(caller)?;
+    Ok(DigestConsumedResponse {
+        consumed: is_digest_consumed(&deps.storage, &caller, digest),
+    })
+}
+
+/// Applies `hash_function` to `message`. With `HashFunction::None` the message is returned
+/// unchanged and must already be a 32-byte digest.
+pub(crate) fn hash_message(message: &[u8], hash_function: HashFunction) -> Vec<u8> {
+    match hash_function {
+        HashFunction::Sha256 => Sha256::digest(message).to_vec(),
+        HashFunction::Keccak256 => Keccak256::digest(message).to_vec(),
+        HashFunction::None => message.to_vec(),
+    }
+}
+
+pub fn query_list_verifications<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+) -> StdResult<ListVerificationsResponse> {
+    let verification_schemes: Vec<_> = list_verifications(deps);
+    Ok(ListVerificationsResponse {
+        verification_schemes,
+    })
+}
+
+pub(crate) fn list_verifications<S: Storage, A: Api, Q: Querier>(
+    _deps: &Extern<S, A, Q>,
+) -> Vec<String> {
+    verification_schemes()
+        .into_iter()
+        .map(|capabilities| capabilities.scheme)
+        .collect()
+}
+
+pub fn query_capabilities<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+) -> StdResult<CapabilitiesResponse> {
+    Ok(CapabilitiesResponse {
+        version: get_contract_version(&deps.storage)?,
+        schemes: verification_schemes(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls::{hash_to_g1, DST_G1_SIGNATURES, DST_G2_SIGNATURES};
+    use axelar_gateway_contracts::crypto::{
+        Eip712Field, EntryFailure, FailureReason, HashMode, KeyEncoding, SchemeCapabilities,
+    };
+    use bech32::ToBase32;
+    use bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
+    use bls12_381::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+    use cosmwasm_crypto::CryptoError;
+    use cosmwasm_std::{
+        from_binary,
+        testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage},
+        HumanAddr,
+    };
+    use cosmwasm_std::{from_slice, Binary, StdError};
+    use hex_literal::hex;
+    use k256::{
+        ecdsa::{
+            signature::DigestSigner, signature::Signer, signature::Verifier, Signature, SigningKey,
+            VerifyingKey,
+        },
+        elliptic_curve::sec1::ToEncodedPoint,
+        CompressedPoint, EncodedPoint, PublicKey, SecretKey,
+    };
+    use rand_core::OsRng;
+    use sha2::{Digest, Sha256};
+    use std::convert::TryInto;
+
+    const CREATOR: &str = "creator";
+
+    // TEST 3 test vector from https://tools.ietf.org/html/rfc8032#section-7.1
+    const ED25519_MESSAGE_HEX: &str = "af82";
+    const ED25519_SIGNATURE_HEX: &str = "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a";
+    const ED25519_PUBLIC_KEY_HEX: &str =
+        "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025";
+
+    // TEST 2 test vector from https://tools.ietf.org/html/rfc8032#section-7.1
+    const ED25519_MESSAGE2_HEX: &str = "72";
+    const ED25519_SIGNATURE2_HEX: &str = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+    const ED25519_PUBLIC_KEY2_HEX: &str =
+        "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+
+    // Signed text "connect all the things" using MyEtherWallet with private key b5b1870957d373ef0eeffecc6e4812c0fd08f554b37b233526acc331bf1544f7
+    const ETHEREUM_MESSAGE: &str = "connect all the things";
+    const ETHEREUM_SIGNATURE_HEX: &str = "dada130255a447ecf434a2df9193e6fbba663e4546c35c075cd6eea21d8c7cb1714b9b65a4f7f604ff6aad55fba73f8c36514a512bbbba03709b37069194f8a41b";
+    const ETHEREUM_SIGNER_ADDRESS: &str = "0x12890D2cce102216644c59daE5baed380d84830c";
+
+    const SECP256K1_MESSAGE_HEX: &str = "5c868fedb8026979ebd26f1ba07c27eedf4ff6d10443505a96ecaf21ba8c4f0937b3cd23ffdc3dd429d4cd1905fb8dbcceeff1350020e18b58d2ba70887baa3a9b783ad30d3fbf210331cdd7df8d77defa398cdacdfc2e359c7ba4cae46bb74401deb417f8b912a1aa966aeeba9c39c7dd22479ae2b30719dca2f2206c5eb4b7";
+    const SECP256K1_SIGNATURE_HEX: &str = "207082eb2c3dfa0b454e0906051270ba4074ac93760ba9e7110cd9471475111151eb0dbbc9920e72146fb564f99d039802bf6ef2561446eb126ef364d21ee9c4";
+    const SECP256K1_PUBLIC_KEY_HEX: &str = "04051c1ee2190ecfb174bfe4f90763f2b4ff7517b70a2aec1876ebcfd644c4633fb03f3cfbd94b1f376e34592d9d41ccaf640bb751b00a1fadeb0c01157769eb73";
+
+    #[test]
+    fn comsos_verify_message_batch() {
+        // Signing
+        let signing_key = SigningKey::random(&mut OsRng); // Serialize with `::to_bytes()`
+        let pub_key = VerifyingKey::from(&signing_key); // Serialize with `::to_encoded_point()`
+
+        let msg = InitMsg {};
+        let messages = vec![msg.clone(), msg.clone(), msg.clone()];
+        let digest = Sha256::digest(to_binary(&messages).unwrap().as_slice());
+
+        let signature: Signature = signing_key.sign(&digest);
+
+        let verify_msg = QueryMsg::VerifyCosmosSignature {
+            message: Binary::from(digest.as_slice()),
+            signature: Binary::from(signature.as_ref()),
+            public_key: Binary::from(pub_key.to_encoded_point(false).as_bytes()),
+        };
+
+        let deps = setup();
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+    }
+
+    fn setup() -> Extern<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies(20, &[]);
+        let msg = InitMsg {};
+        let res = init(&mut deps, mock_env(HumanAddr::from("addr01"), &[]), msg).unwrap();
+        assert_eq!(0, res.messages.len());
+        deps
+    }
+
+    /// Queries a verification with malformed inputs and returns the reported failure reason.
+    fn failure_reason(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        verify_msg: QueryMsg,
+    ) -> FailureReason {
+        let res: VerifyResponse = from_binary(&query(deps, verify_msg).unwrap()).unwrap();
+        assert!(!res.verifies);
+        res.failure_reason.expect("expected a failure reason")
+    }
+
+    fn entry_failure(index: u32, code: u32, message: &str) -> EntryFailure {
+        EntryFailure {
+            index,
+            failure_reason: FailureReason {
+                code,
+                message: message.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn instantiate_works() {
+        setup();
+    }
+
+    #[test]
+    fn cosmos_signature_verify_works() {
+        let deps = setup();
+
+        let message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
+        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap();
+
+        let verify_msg = QueryMsg::VerifyCosmosSignature {
+            message: Binary(message),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
+        };
+
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn cosmos_signature_verify_fails() {
+        let deps = setup();
+
+        let mut message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
+        // alter message
+        message[0] ^= 0x01;
+        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap();
+
+        let verify_msg = QueryMsg::VerifyCosmosSignature {
+            message: Binary(message),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
+        };
+
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn cosmos_signature_verify_errors() {
+        let deps = setup();
+
+        let message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
+        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
+        let public_key = vec![];
+
+        let verify_msg = QueryMsg::VerifyCosmosSignature {
+            message: Binary(message),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
+        };
+
+        // malformed inputs do not verify and carry the reason
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: Some(FailureReason {
+                    code: 5,
+                    message: "Invalid public key format".to_string(),
+                }),
+            }
+        );
+
+        let verify_msg = QueryMsg::VerifyCosmosSignature {
+            message: Binary(hex::decode(SECP256K1_MESSAGE_HEX).unwrap()),
+            signature: Binary(hex::decode(SECP256K1_SIGNATURE_HEX).unwrap()[..63].to_vec()),
+            public_key: Binary(hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap()),
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert!(!res.verifies);
+        assert_eq!(
+            res.failure_reason,
+            Some(FailureReason {
+                code: 4,
+                message: "Invalid signature format".to_string(),
+            })
+        );
+    }
+
+    /// Signs `data` for `prefix` the way Keplr's `signArbitrary` does.
+    /// Returns (signer, signature, public key).
+    fn adr036_sign(
+        signing_key: &SigningKey,
+        prefix: &str,
+        data: &[u8],
+    ) -> (String, Binary, Binary) {
+        let public_key = VerifyingKey::from(signing_key).to_bytes().to_vec();
+        let signer = address_from_pubkey(prefix, &public_key).unwrap();
+        let signature: Signature = signing_key.sign(adr036::sign_doc(&signer, data).as_bytes());
+        (signer, Binary::from(signature.as_ref()), Binary(public_key))
+    }
+
+    #[test]
+    fn adr036_signature_verify_works() {
+        let deps = setup();
+        let signing_key = SigningKey::random(&mut OsRng);
+        let data = b"execute batch 42".to_vec();
+
+        for prefix in &["terra", "cosmos", "axelar"] {
+            let (signer, signature, public_key) = adr036_sign(&signing_key, prefix, &data);
+            let verify_msg = QueryMsg::VerifyAdr036Signature {
+                signer,
+                data: Binary(data.clone()),
+                signature,
+                public_key,
+            };
+            let raw = query(&deps, verify_msg).unwrap();
+            let res: VerifyResponse = from_binary(&raw).unwrap();
+            assert_eq!(
+                res,
+                VerifyResponse {
+                    verifies: true,
+                    failure_reason: None
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn adr036_signature_verify_fails() {
+        let deps = setup();
+        let signing_key = SigningKey::random(&mut OsRng);
+        let data = b"execute batch 42".to_vec();
+        let (signer, signature, public_key) = adr036_sign(&signing_key, "terra", &data);
+
+        let verifies = |signer: &str, data: &[u8], public_key: &Binary| {
+            let verify_msg = QueryMsg::VerifyAdr036Signature {
+                signer: signer.to_string(),
+                data: Binary(data.to_vec()),
+                signature: signature.clone(),
+                public_key: public_key.clone(),
+            };
+            let raw = query(&deps, verify_msg).unwrap();
+            let res: VerifyResponse = from_binary(&raw).unwrap();
+            res.verifies
+        };
+
+        assert!(verifies(&signer, &data, &public_key));
+        // other data
+        assert!(!verifies(&signer, b"execute batch 43", &public_key));
+        // other signer
+        let other_key = SigningKey::random(&mut OsRng);
+        let (other_signer, _, other_public_key) = adr036_sign(&other_key, "terra", &data);
+        assert!(!verifies(&other_signer, &data, &public_key));
+        // the signer must match the public key
+        assert!(!verifies(&signer, &data, &other_public_key));
+    }
+
+    #[test]
+    fn adr036_signature_verify_errors() {
+        let deps = setup();
+        let signing_key = SigningKey::random(&mut OsRng);
+        let data = b"execute batch 42".to_vec();
+        let (signer, signature, public_key) = adr036_sign(&signing_key, "terra", &data);
+
+        let verify_msg = QueryMsg::VerifyAdr036Signature {
+            signer: format!("{}\"", signer),
+            data: Binary(data.clone()),
+            signature: signature.clone(),
+            public_key: public_key.clone(),
+        };
+        let reason = failure_reason(&deps, verify_msg);
+        assert_eq!(reason.code, 100);
+        assert!(
+            reason.message.starts_with("Invalid signer address: "),
+            "{}",
+            reason.message
+        );
+
+        let verify_msg = QueryMsg::VerifyAdr036Signature {
+            signer,
+            data: Binary(data),
+            signature,
+            public_key: Binary(public_key.0[1..].to_vec()),
+        };
+        assert_eq!(
+            failure_reason(&deps, verify_msg),
+            FailureReason {
+                code: 5,
+                message: "Invalid public key format".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn secp256k1_signature_verify_hash_functions() {
+        let deps = setup();
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let public_key = Binary::from(VerifyingKey::from(&signing_key).to_bytes().as_slice());
+        let message = b"connect all the things".to_vec();
+
+        let verifies = |message: Vec<u8>, signature: &Signature, hash_function| {
+            let verify_msg = QueryMsg::VerifySecp256k1Signature {
+                message: Binary(message),
+                signature: Binary::from(signature.as_ref()),
+                public_key: public_key.clone(),
+                hash_function,
+            };
+            let raw = query(&deps, verify_msg).unwrap();
+            let res: VerifyResponse = from_binary(&raw).unwrap();
+            res.verifies
+        };
+
+        // sha256
+        let signature: Signature = signing_key.sign(&message);
+        assert!(verifies(message.clone(), &signature, HashFunction::Sha256));
+        assert!(!verifies(
+            message.clone(),
+            &signature,
+            HashFunction::Keccak256
+        ));
+
+        // keccak256
+        let signature: Signature =
+            signing_key.sign_digest(Keccak256::new().chain(message.as_slice()));
+        assert!(verifies(
+            message.clone(),
+            &signature,
+            HashFunction::Keccak256
+        ));
+        assert!(!verifies(message.clone(), &signature, HashFunction::Sha256));
+
+        // prehashed
+        let signature: Signature = signing_key.sign(&message);
+        let digest = Sha256::digest(&message).to_vec();
+        assert!(verifies(digest.clone(), &signature, HashFunction::None));
+        assert!(!verifies(digest, &signature, HashFunction::Sha256));
+    }
+
+    #[test]
+    fn secp256k1_signature_verify_prehashed_errors() {
+        let deps = setup();
+
+        // a prehashed message must be a 32-byte digest
+        let message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
+        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap();
+
+        let verify_msg = QueryMsg::VerifySecp256k1Signature {
+            message: Binary(message),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
+            hash_function: HashFunction::None,
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert!(!res.verifies);
+        assert_eq!(
+            res.failure_reason,
+            Some(FailureReason {
+                code: 3,
+                message: "Invalid hash format".to_string(),
+            })
+        );
+    }
+
+    // Test data calculated via Secp256k1.createSignature from @cosmjs/crypto
+    // (see cosmwasm-crypto's secp256k1_recover_pubkey_works)
+    const SECP256K1_RECOVER_MESSAGE_HASH_HEX: &str =
+        "5ae8317d34d1e595e3fa7247db80c0af4320cce1116de187f8f7e2e099c0d8d0";
+    const SECP256K1_RECOVER_SIGNATURE_HEX: &str = "45c0b7f8c09a9e1f1cea0c25785594427b6bf8f9f878a8af0b1abbb48e16d0920d8becd0c220f67c51217eecfd7184ef0732481c843857e6bc7fc095c4f6b78801";
+    const SECP256K1_RECOVER_PUBLIC_KEY_HEX: &str = "044a071e8a6e10aada2b8cf39fa3b5fb3400b04e99ea8ae64ceea1a977dbeaf5d5f8c8fbd10b71ab14cd561f7df8eb6da50f8a8d81ba564342244d26d1d4211595";
+
+    fn verify_secp256k1_batch(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        messages: Vec<Vec<u8>>,
+        signatures: Vec<Vec<u8>>,
+        public_keys: Vec<Vec<u8>>,
+        hash_function: HashFunction,
+    ) -> StdResult<VerifySecp256k1BatchResponse> {
+        let verify_msg = QueryMsg::VerifySecp256k1Batch {
+            messages: messages.into_iter().map(Binary).collect(),
+            signatures: signatures.into_iter().map(Binary).collect(),
+            public_keys: public_keys.into_iter().map(Binary).collect(),
+            hash_function,
+        };
+        query(deps, verify_msg).map(|raw| from_binary(&raw).unwrap())
+    }
+
+    #[test]
+    fn secp256k1_batch_verify_works() {
+        let deps = setup();
+
+        let signing_keys: Vec<SigningKey> =
+            (0..3).map(|_| SigningKey::random(&mut OsRng)).collect();
+        let public_keys: Vec<Vec<u8>> = signing_keys
+            .iter()
+            .map(|k| VerifyingKey::from(k).to_bytes().to_vec())
+            .collect();
+        let messages: Vec<Vec<u8>> = (0..3)
+            .map(|i| format!("message {}", i).into_bytes())
+            .collect();
+
+        // parallel arrays
+        let signatures = signing_keys
+            .iter()
+            .zip(messages.iter())
+            .map(|(k, m)| Signature::as_ref(&k.sign(m)).to_vec())
+            .collect();
+        let res = verify_secp256k1_batch(
+            &deps,
+            messages.clone(),
+            signatures,
+            public_keys.clone(),
+            HashFunction::Sha256,
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            VerifySecp256k1BatchResponse {
+                verifies: true,
+                results: vec![true, true, true],
+                failure_reason: None,
+                entry_failures: vec![],
+            }
+        );
+
+        // shared message
+        let signatures = signing_keys
+            .iter()
+            .map(|k| Signature::as_ref(&k.sign(&messages[0])).to_vec())
+            .collect();
+        let res = verify_secp256k1_batch(
+            &deps,
+            vec![messages[0].clone()],
+            signatures,
+            public_keys.clone(),
+            HashFunction::Sha256,
+        )
+        .unwrap();
+        assert!(res.verifies);
+
+        // shared key, prehashed
+        let digests: Vec<Vec<u8>> = messages
+            .iter()
+            .map(|m| Sha256::digest(m).to_vec())
+            .collect();
+        let signatures = messages
+            .iter()
+            .map(|m| Signature::as_ref(&signing_keys[0].sign(m)).to_vec())
+            .collect();
+        let res = verify_secp256k1_batch(
+            &deps,
+            digests,
+            signatures,
+            vec![public_keys[0].clone()],
+            HashFunction::None,
+        )
+        .unwrap();
+        assert_eq!(res.results, vec![true, true, true]);
+
+        // empty batch
+        let res =
+            verify_secp256k1_batch(&deps, vec![], vec![], vec![], HashFunction::Sha256).unwrap();
+        assert!(res.verifies);
+    }
+
+    #[test]
+    fn secp256k1_batch_verify_fails() {
+        let deps = setup();
+
+        let message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
+        let mut altered = message.clone();
+        altered[0] ^= 0x01;
+        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap();
+
+        let res = verify_secp256k1_batch(
+            &deps,
+            vec![message.clone(), altered, message],
+            vec![signature.clone(), signature.clone(), signature],
+            vec![public_key],
+            HashFunction::Sha256,
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            VerifySecp256k1BatchResponse {
+                verifies: false,
+                results: vec![true, false, true],
+                failure_reason: None,
+                entry_failures: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn secp256k1_batch_verify_malformed_entries() {
+        let deps = setup();
+
+        let message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
+        let mut altered = message.clone();
+        altered[0] ^= 0x01;
+        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
+        let truncated = signature[..63].to_vec();
+        let public_key = hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap();
+
+        // valid, invalid and malformed signatures
+        let res = verify_secp256k1_batch(
+            &deps,
+            vec![message.clone(), altered, message.clone()],
+            vec![signature.clone(), signature.clone(), truncated],
+            vec![public_key.clone()],
+            HashFunction::Sha256,
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            VerifySecp256k1BatchResponse {
+                verifies: false,
+                results: vec![true, false, false],
+                failure_reason: None,
+                entry_failures: vec![entry_failure(2, 4, "Invalid signature format")],
+            }
+        );
+
+        // an empty public key
+        let res = verify_secp256k1_batch(
+            &deps,
+            vec![message],
+            vec![signature.clone(), signature],
+            vec![public_key, vec![]],
+            HashFunction::Sha256,
+        )
+        .unwrap();
+        assert_eq!(res.results, vec![true, false]);
+        assert_eq!(
+            res.entry_failures,
+            vec![entry_failure(1, 5, "Invalid public key format")]
+        );
+    }
+
+    #[test]
+    fn secp256k1_batch_verify_errors() {
+        let deps = setup();
+
+        let message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
+        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap();
+
+        // Mismatched shapes
+        let res = verify_secp256k1_batch(
+            &deps,
+            vec![message.clone(), message],
+            vec![signature],
+            vec![public_key.clone(), public_key],
+            HashFunction::Sha256,
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            VerifySecp256k1BatchResponse {
+                verifies: false,
+                results: vec![],
+                failure_reason: Some(FailureReason {
+                    code: 7,
+                    message: "Batch error: Mismatched / erroneous number of messages (2) / signatures (1) / public keys (2)".to_string(),
+                }),
+                entry_failures: vec![],
+            }
+        );
+    }
+
+    struct SignerSet {
+        keys: Vec<SigningKey>,
+        signers: Vec<WeightedSigner>,
+    }
+
+    fn signer_set(weights: &[u128]) -> SignerSet {
+        let keys: Vec<SigningKey> = weights
+            .iter()
+            .map(|_| SigningKey::random(&mut OsRng))
+            .collect();
+        let signers = keys
+            .iter()
+            .zip(weights)
+            .map(|(key, weight)| WeightedSigner {
+                public_key: Binary::from(VerifyingKey::from(key).to_bytes().as_slice()),
+                weight: Uint128(*weight),
+            })
+            .collect();
+        SignerSet { keys, signers }
+    }
+
+    fn verify_threshold(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        set: &SignerSet,
+        threshold: u128,
+        signatures: Vec<SignerSignature>,
+    ) -> StdResult<VerifyThresholdResponse> {
+        let verify_msg = QueryMsg::VerifyThreshold {
+            message: Binary::from(THRESHOLD_MESSAGE),
+            hash_function: HashFunction::Sha256,
+            signers: set.signers.clone(),
+            threshold: Uint128(threshold),
+            signatures,
+        };
+        query(deps, verify_msg).map(|raw| from_binary(&raw).unwrap())
+    }
+
+    const THRESHOLD_MESSAGE: &[u8] = b"execute batch 7";
+
+    fn sign_as(set: &SignerSet, signer_index: u32) -> SignerSignature {
+        let signature: Signature = set.keys[signer_index as usize].sign(THRESHOLD_MESSAGE);
+        SignerSignature {
+            signer_index,
+            signature: Binary::from(signature.as_ref()),
+        }
+    }
+
+    #[test]
+    fn threshold_verify_works() {
+        let deps = setup();
+        let set = signer_set(&[10, 20, 30, 40]);
+
+        let res =
+            verify_threshold(&deps, &set, 60, vec![sign_as(&set, 3), sign_as(&set, 1)]).unwrap();
+        assert_eq!(
+            res,
+            VerifyThresholdResponse {
+                threshold_met: true,
+                signed_weight: Uint128(60),
+                verified_signers: vec![3, 1],
+                failure_reason: None,
+                entry_failures: vec![],
+            }
+        );
+
+        let res =
+            verify_threshold(&deps, &set, 61, vec![sign_as(&set, 3), sign_as(&set, 1)]).unwrap();
+        assert!(!res.threshold_met);
+        assert_eq!(res.signed_weight, Uint128(60));
+    }
+
+    #[test]
+    fn threshold_verify_ignores_invalid_signatures() {
+        let deps = setup();
+        let set = signer_set(&[1, 1, 1]);
+
+        // signer 1 signs, but claims to be signer 2
+        let mut forged = sign_as(&set, 1);
+        forged.signer_index = 2;
+        let res = verify_threshold(&deps, &set, 2, vec![sign_as(&set, 0), forged]).unwrap();
+        assert_eq!(
+            res,
+            VerifyThresholdResponse {
+                threshold_met: false,
+                signed_weight: Uint128(1),
+                verified_signers: vec![0],
+                failure_reason: None,
+                entry_failures: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn threshold_verify_errors() {
+        let deps = setup();
+        let set = signer_set(&[1, 1, 1]);
+
+        let expect_err = |res: StdResult<VerifyThresholdResponse>, expected: &str| {
+            assert_eq!(
+                res.unwrap(),
+                VerifyThresholdResponse {
+                    threshold_met: false,
+                    signed_weight: Uint128::zero(),
+                    verified_signers: vec![],
+                    failure_reason: Some(FailureReason {
+                        code: 102,
+                        message: expected.to_string(),
+                    }),
+                    entry_failures: vec![],
+                }
+            )
+        };
+
+        expect_err(
+            verify_threshold(&deps, &set, 2, vec![sign_as(&set, 0), sign_as(&set, 0)]),
+            "Duplicate signature for signer 0",
+        );
+
+        let mut out_of_range = sign_as(&set, 0);
+        out_of_range.signer_index = 3;
+        expect_err(
+            verify_threshold(&deps, &set, 1, vec![out_of_range]),
+            "Signer index 3 out of range (3 signers)",
+        );
+
+        expect_err(
+            verify_threshold(&deps, &set, 0, vec![]),
+            "Threshold must be greater than zero",
+        );
+
+        let heavy = signer_set(&[u128::MAX, 1]);
+        expect_err(
+            verify_threshold(&deps, &heavy, 1, vec![sign_as(&heavy, 0)]),
+            "Total signer weight overflow",
+        );
+
+        let zero_weight = signer_set(&[1, 0, 1]);
+        expect_err(
+            verify_threshold(&deps, &zero_weight, 1, vec![sign_as(&zero_weight, 0)]),
+            "Signer 1 has zero weight",
+        );
+
+        let empty = signer_set(&[]);
+        expect_err(
+            verify_threshold(&deps, &empty, 1, vec![]),
+            "Total signer weight must be greater than zero",
+        );
+    }
+
+    #[test]
+    fn threshold_verify_malformed_key_fails_its_signer() {
+        let deps = setup();
+        let mut set = signer_set(&[1, 1, 1]);
+        set.signers[1].public_key = Binary(vec![0x02; 12]);
+        set.signers[2].public_key = Binary(vec![]);
+
+        // signer 2 did not sign, so its malformed key does not matter
+        let res = verify_threshold(&deps, &set, 1, vec![sign_as(&set, 0)]).unwrap();
+        assert_eq!(
+            res,
+            VerifyThresholdResponse {
+                threshold_met: true,
+                signed_weight: Uint128(1),
+                verified_signers: vec![0],
+                failure_reason: None,
+                entry_failures: vec![],
+            }
+        );
+
+        // signer 1 signed, but its key is malformed
+        let res =
+            verify_threshold(&deps, &set, 2, vec![sign_as(&set, 0), sign_as(&set, 1)]).unwrap();
+        assert_eq!(
+            res,
+            VerifyThresholdResponse {
+                threshold_met: false,
+                signed_weight: Uint128(1),
+                verified_signers: vec![0],
+                failure_reason: None,
+                entry_failures: vec![entry_failure(1, 5, "Invalid public key format")],
+            }
+        );
+    }
+
+    #[test]
+    fn threshold_verify_malformed_signature_fails_its_signer() {
+        let deps = setup();
+        let set = signer_set(&[10, 20, 30]);
+
+        let mut truncated = sign_as(&set, 0);
+        truncated.signature.0.pop();
+        let mut garbled = sign_as(&set, 1);
+        garbled.signature = Binary(b"not a signature".to_vec());
+
+        let res =
+            verify_threshold(&deps, &set, 30, vec![truncated, garbled, sign_as(&set, 2)]).unwrap();
+        assert_eq!(
+            res,
+            VerifyThresholdResponse {
+                threshold_met: true,
+                signed_weight: Uint128(30),
+                verified_signers: vec![2],
+                failure_reason: None,
+                entry_failures: vec![
+                    entry_failure(0, 4, "Invalid signature format"),
+                    entry_failure(1, 4, "Invalid signature format"),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn recover_pubkey_works() {
+        let deps = setup();
+
+        let recover_msg = QueryMsg::RecoverPubkey {
+            message: Binary(hex::decode(SECP256K1_RECOVER_MESSAGE_HASH_HEX).unwrap()),
+            signature: Binary(hex::decode(SECP256K1_RECOVER_SIGNATURE_HEX).unwrap()),
+            hash_function: HashFunction::None,
+        };
+        let raw = query(&deps, recover_msg).unwrap();
+        let res: RecoverPubkeyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res.public_key,
+            Binary(hex::decode(SECP256K1_RECOVER_PUBLIC_KEY_HEX).unwrap())
+        );
+
+        // Ethereum style recovery id (27/28)
+        let mut signature = hex::decode(SECP256K1_RECOVER_SIGNATURE_HEX).unwrap();
+        signature[64] += 27;
+        let recover_msg = QueryMsg::RecoverPubkey {
+            message: Binary(hex::decode(SECP256K1_RECOVER_MESSAGE_HASH_HEX).unwrap()),
+            signature: Binary(signature),
+            hash_function: HashFunction::None,
+        };
+        let raw = query(&deps, recover_msg).unwrap();
+        let res: RecoverPubkeyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res.public_key,
+            Binary(hex::decode(SECP256K1_RECOVER_PUBLIC_KEY_HEX).unwrap())
+        );
+    }
+
+    #[test]
+    fn recover_pubkey_matches_signer() {
+        let deps = setup();
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let message = b"connect all the things".to_vec();
+        let signature: Signature = signing_key.sign(&message);
+
+        // the recovery id is whichever one recovers the signer's key
+        let recovered: Vec<Binary> = (0u8..2)
+            .filter_map(|v| {
+                let mut recoverable = signature.as_ref().to_vec();
+                recoverable.push(v);
+                let recover_msg = QueryMsg::RecoverPubkey {
+                    message: Binary(message.clone()),
+                    signature: Binary(recoverable),
+                    hash_function: HashFunction::Sha256,
+                };
+                query(&deps, recover_msg).ok().map(|raw| {
+                    from_binary::<RecoverPubkeyResponse>(&raw)
+                        .unwrap()
+                        .public_key
+                })
+            })
+            .collect();
+        let expected = Binary::from(verifying_key.to_encoded_point(false).as_bytes());
+        assert!(recovered.contains(&expected));
+    }
+
+    #[test]
+    fn recover_pubkey_errors() {
+        let deps = setup();
+
+        // missing recovery id
+        let mut signature = hex::decode(SECP256K1_RECOVER_SIGNATURE_HEX).unwrap();
+        signature.pop();
+        let recover_msg = QueryMsg::RecoverPubkey {
+            message: Binary(hex::decode(SECP256K1_RECOVER_MESSAGE_HASH_HEX).unwrap()),
+            signature: Binary(signature.clone()),
+            hash_function: HashFunction::None,
+        };
+        match query(&deps, recover_msg) {
+            Err(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "Recoverable signature must be 65 bytes long, got 64")
+            }
+            res => panic!("expected error, got {:?}", res),
+        }
+
+        // invalid recovery id
+        signature.push(2);
+        let recover_msg = QueryMsg::RecoverPubkey {
+            message: Binary(hex::decode(SECP256K1_RECOVER_MESSAGE_HASH_HEX).unwrap()),
+            signature: Binary(signature),
+            hash_function: HashFunction::None,
+        };
+        match query(&deps, recover_msg) {
+            Err(StdError::GenericErr { msg, .. }) => assert_eq!(
+                msg,
+                "Invalid recovery id 2. Supported values: 0, 1, 27 and 28."
+            ),
+            res => panic!("expected error, got {:?}", res),
+        }
+    }
+
+    // Test data from https://github.com/bitcoinjs/bitcoinjs-message/blob/v2.2.0/README.md
+    const BITCOIN_MESSAGE: &str = "This is an example of a signed message.";
+    const BITCOIN_SIGNATURE: &str =
+        "H9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk=";
+    const BITCOIN_ADDRESS: &str = "1F3sAm6ZtwLAUnj7d38pGFxtP3RVEvtsbV";
+
+    fn verify_bitcoin_message(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        message: &str,
+        signature: Vec<u8>,
+        address: &str,
+    ) -> StdResult<bool> {
+        let verify_msg = QueryMsg::VerifyBitcoinMessage {
+            message: message.to_string(),
+            signature: Binary(signature),
+            address: address.to_string(),
+        };
+        query(deps, verify_msg).map(|raw| from_binary::<VerifyResponse>(&raw).unwrap().verifies)
+    }
+
+    /// Signs `message` as Bitcoin signed message with the header for the recovery id and
+    /// `header_offset` (27 uncompressed, 31 compressed, 39 P2WPKH).
+    fn bitcoin_sign(signing_key: &SigningKey, message: &str, header_offset: u8) -> Vec<u8> {
+        let hash = bitcoin::message_hash(message.as_bytes());
+        // the digest signer finalizes the second round of sha256; messages here are < 253 bytes
+        let first_hash = Sha256::new()
+            .chain(b"\x18Bitcoin Signed Message:\n")
+            .chain([message.len() as u8])
+            .chain(message)
+            .finalize();
+        let signature: Signature = signing_key.sign_digest(Sha256::new().chain(first_hash));
+        let public_key = VerifyingKey::from(signing_key).to_encoded_point(false);
+        let recovery_param = (0..2)
+            .find(|v| {
+                recover_pubkey(&hash, &[signature.as_ref(), &[*v]].concat()).unwrap()
+                    == public_key.as_bytes()
+            })
+            .unwrap();
+        [&[header_offset + recovery_param], signature.as_ref()].concat()
+    }
+
+    fn base58check_encode(payload: &[u8]) -> String {
+        const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+        let checksum = Sha256::digest(&Sha256::digest(payload));
+        let data = [payload, &checksum[..4]].concat();
+        let mut digits: Vec<u8> = vec![];
+        for byte in &data {
+            let mut carry = *byte as u32;
+            for digit in digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+        let zeros = data.iter().take_while(|b| **b == 0).count();
+        let mut encoded = vec![b'1'; zeros];
+        encoded.extend(digits.iter().rev().map(|d| ALPHABET[*d as usize]));
+        String::from_utf8(encoded).unwrap()
+    }
+
+    #[test]
+    fn bitcoin_message_verify_works() {
+        let deps = setup();
+
+        let signature = Binary::from_base64(BITCOIN_SIGNATURE).unwrap().0;
+        assert!(
+            verify_bitcoin_message(&deps, BITCOIN_MESSAGE, signature, BITCOIN_ADDRESS).unwrap()
+        );
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let public_key = VerifyingKey::from(&signing_key).to_encoded_point(false);
+        let message = "attest: 0.5 BTC locked in 4a5e1e4b";
+
+        // uncompressed P2PKH, on mainnet and testnet
+        let hash = bitcoin::hash160(public_key.as_bytes());
+        for version in &[0x00, 0x6f] {
+            let address = base58check_encode(&[&[*version], &hash[..]].concat());
+            let signature = bitcoin_sign(&signing_key, message, 27);
+            assert!(verify_bitcoin_message(&deps, message, signature, &address).unwrap());
+        }
+
+        // compressed P2WPKH, with BIP-137 and Electrum headers
+        let hash = bitcoin::hash160(public_key.compress().as_bytes());
+        let mut program = vec![bech32::u5::try_from_u8(0).unwrap()];
+        program.extend(hash.to_base32());
+        let address = bech32::encode("bc", program).unwrap();
+        for header_offset in &[39, 31] {
+            let signature = bitcoin_sign(&signing_key, message, *header_offset);
+            assert!(verify_bitcoin_message(&deps, message, signature, &address).unwrap());
+        }
+    }
+
+    #[test]
+    fn bitcoin_message_verify_fails() {
+        let deps = setup();
+        let signature = Binary::from_base64(BITCOIN_SIGNATURE).unwrap().0;
+
+        // other message
+        assert!(!verify_bitcoin_message(
+            &deps,
+            "Other message",
+            signature.clone(),
+            BITCOIN_ADDRESS
+        )
+        .unwrap());
+
+        // other address
+        assert!(!verify_bitcoin_message(
+            &deps,
+            BITCOIN_MESSAGE,
+            signature.clone(),
+            "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"
+        )
+        .unwrap());
+
+        // uncompressed header for a compressed key address
+        let mut uncompressed = signature;
+        uncompressed[0] -= 4;
+        assert!(
+            !verify_bitcoin_message(&deps, BITCOIN_MESSAGE, uncompressed, BITCOIN_ADDRESS).unwrap()
+        );
+
+        // uncompressed keys have no P2WPKH address
+        let signing_key = SigningKey::random(&mut OsRng);
+        let public_key = VerifyingKey::from(&signing_key).to_encoded_point(false);
+        let mut program = vec![bech32::u5::try_from_u8(0).unwrap()];
+        program.extend(bitcoin::hash160(public_key.as_bytes()).to_base32());
+        let address = bech32::encode("bc", program).unwrap();
+        let signature = bitcoin_sign(&signing_key, BITCOIN_MESSAGE, 27);
+        assert!(!verify_bitcoin_message(&deps, BITCOIN_MESSAGE, signature, &address).unwrap());
+    }
+
+    #[test]
+    fn bitcoin_message_verify_errors() {
+        let deps = setup();
+        let signature = Binary::from_base64(BITCOIN_SIGNATURE).unwrap().0;
+        let verify_msg = |signature: Vec<u8>, address: &str| QueryMsg::VerifyBitcoinMessage {
+            message: BITCOIN_MESSAGE.to_string(),
+            signature: Binary(signature),
+            address: address.to_string(),
+        };
+
+        let mut invalid_header = signature.clone();
+        invalid_header[0] = 43;
+        assert_eq!(
+            failure_reason(&deps, verify_msg(invalid_header, BITCOIN_ADDRESS)),
+            FailureReason {
+                code: 101,
+                message: "Invalid signature header 43. Supported values: 27 to 42.".to_string(),
+            }
+        );
+        assert_eq!(
+            failure_reason(&deps, verify_msg(signature[..64].to_vec(), BITCOIN_ADDRESS)),
+            FailureReason {
+                code: 101,
+                message: "Signature must be 65 bytes long, got 64".to_string(),
+            }
+        );
+        assert_eq!(
+            failure_reason(&deps, verify_msg(signature, "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy")),
+            FailureReason {
+                code: 100,
+                message: "Invalid Bitcoin address 3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy: only P2PKH and P2WPKH addresses are supported".to_string(),
+            }
+        );
+    }
+
+    // Example of the EIP-712 specification, signed with the private key keccak256("cow"), see
+    // https://github.com/ethereum/EIPs/blob/master/assets/eip-712/Example.js
+    const EIP712_MAIL_MESSAGE: &str = r#"{"from":{"name":"Cow","wallet":"0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},"to":{"name":"Bob","wallet":"0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},"contents":"Hello, Bob!"}"#;
+    const EIP712_MAIL_SIGNATURE_HEX: &str = "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b915621c";
+    const EIP712_MAIL_SIGNER: &str = "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826";
+
+    fn eip712_mail_domain() -> Eip712Domain {
+        Eip712Domain {
+            name: Some("Ether Mail".to_string()),
+            version: Some("1".to_string()),
+            chain_id: Some(Uint128(1)),
+            verifying_contract: Some("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".to_string()),
+            salt: None,
+        }
+    }
+
+    fn eip712_mail_types() -> Vec<Eip712Type> {
+        let field = |name: &str, field_type: &str| Eip712Field {
+            name: name.to_string(),
+            field_type: field_type.to_string(),
+        };
+        vec![
+            Eip712Type {
+                name: "Mail".to_string(),
+                fields: vec![
+                    field("from", "Person"),
+                    field("to", "Person"),
+                    field("contents", "string"),
+                ],
+            },
+            Eip712Type {
+                name: "Person".to_string(),
+                fields: vec![field("name", "string"), field("wallet", "address")],
+            },
+        ]
+    }
+
+    #[test]
+    fn eip712_signature_verify_works() {
+        let deps = setup();
+
+        let verify_msg = QueryMsg::VerifyEip712Signature {
+            domain: eip712_mail_domain(),
+            types: eip712_mail_types(),
+            primary_type: "Mail".to_string(),
+            message: EIP712_MAIL_MESSAGE.to_string(),
+            signature: Binary(hex::decode(EIP712_MAIL_SIGNATURE_HEX).unwrap()),
+            signer_address: EIP712_MAIL_SIGNER.to_string(),
+        };
+        let res: VerifyResponse = from_binary(&query(&deps, verify_msg).unwrap()).unwrap();
+        assert!(res.verifies);
+
+        let recover_msg = QueryMsg::RecoverEip712Signer {
+            domain: eip712_mail_domain(),
+            types: eip712_mail_types(),
+            primary_type: "Mail".to_string(),
+            message: EIP712_MAIL_MESSAGE.to_string(),
+            signature: Binary(hex::decode(EIP712_MAIL_SIGNATURE_HEX).unwrap()),
+        };
+        let res: Eip712SignerResponse = from_binary(&query(&deps, recover_msg).unwrap()).unwrap();
+        assert_eq!(
+            res,
+            Eip712SignerResponse {
+                address: EIP712_MAIL_SIGNER.to_string(),
+                signing_hash: Binary(
+                    hex!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+                        .to_vec()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn eip712_signature_verify_fails() {
+        let deps = setup();
+
+        // other message, other chain
+        let message = EIP712_MAIL_MESSAGE.replace("Hello", "Bye");
+        let mut domain = eip712_mail_domain();
+        domain.chain_id = Some(Uint128(5));
+        for (domain, message) in [
+            (eip712_mail_domain(), message.as_str()),
+            (domain, EIP712_MAIL_MESSAGE),
+        ]
+        .iter()
+        {
+            let verify_msg = QueryMsg::VerifyEip712Signature {
+                domain: domain.clone(),
+                types: eip712_mail_types(),
+                primary_type: "Mail".to_string(),
+                message: message.to_string(),
+                signature: Binary(hex::decode(EIP712_MAIL_SIGNATURE_HEX).unwrap()),
+                signer_address: EIP712_MAIL_SIGNER.to_string(),
+            };
+            let res: VerifyResponse = from_binary(&query(&deps, verify_msg).unwrap()).unwrap();
+            assert!(!res.verifies);
+        }
+    }
+
+    #[test]
+    fn eip712_signature_verify_errors() {
+        let deps = setup();
+
+        let verify_msg = QueryMsg::VerifyEip712Signature {
+            domain: eip712_mail_domain(),
+            types: eip712_mail_types()[..1].to_vec(),
+            primary_type: "Mail".to_string(),
+            message: EIP712_MAIL_MESSAGE.to_string(),
+            signature: Binary(hex::decode(EIP712_MAIL_SIGNATURE_HEX).unwrap()),
+            signer_address: EIP712_MAIL_SIGNER.to_string(),
+        };
+        assert_eq!(
+            failure_reason(&deps, verify_msg),
+            FailureReason {
+                code: 102,
+                message: "Invalid EIP-712 typed data: unknown type Person".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn recover_cosmos_address_works() {
+        let deps = setup();
+
+        let recover_msg = QueryMsg::RecoverCosmosAddress {
+            message: Binary(hex::decode(SECP256K1_RECOVER_MESSAGE_HASH_HEX).unwrap()),
+            signature: Binary(hex::decode(SECP256K1_RECOVER_SIGNATURE_HEX).unwrap()),
+            hash_function: HashFunction::None,
+            prefix: "terra".to_string(),
+        };
+        let raw = query(&deps, recover_msg).unwrap();
+        let res: RecoverCosmosAddressResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            RecoverCosmosAddressResponse {
+                address: "terra12nle5gf0mwne5fryc54elvmt690h4lkazhlzs9".to_string(),
+                public_key: Binary(
+                    hex::decode(
+                        "034a071e8a6e10aada2b8cf39fa3b5fb3400b04e99ea8ae64ceea1a977dbeaf5d5"
+                    )
+                    .unwrap()
+                ),
+            }
+        );
+    }
+
+    const UNCOMPRESSED_PUBKEY_HEX: &str = "043a443d8381a6798a70c6ff9304bdc8cb0163c23211d11628fae52ef9e0dca11a001cf066d56a8156fc201cd5df8a36ef694eecd258903fca7086c1fae7441e1d";
+    const COMPRESSED_PUBKEY_HEX: &str =
+        "033a443d8381a6798a70c6ff9304bdc8cb0163c23211d11628fae52ef9e0dca11a";
+
+    #[test]
+    fn compress_pubkey_works() {
+        let deps = setup();
+        let uncompressed = Binary(hex::decode(UNCOMPRESSED_PUBKEY_HEX).unwrap());
+        let compressed = Binary(hex::decode(COMPRESSED_PUBKEY_HEX).unwrap());
+
+        for public_key in [uncompressed.clone(), compressed.clone()].iter() {
+            let msg = QueryMsg::CompressPubkey {
+                public_key: public_key.clone(),
+            };
+            let res: PubkeyResponse = from_binary(&query(&deps, msg).unwrap()).unwrap();
+            assert_eq!(res.public_key, compressed);
+
+            let msg = QueryMsg::DecompressPubkey {
+                public_key: public_key.clone(),
+            };
+            let res: PubkeyResponse = from_binary(&query(&deps, msg).unwrap()).unwrap();
+            assert_eq!(res.public_key, uncompressed);
+        }
+    }
+
+    #[test]
+    fn compress_pubkey_fails_for_invalid_key() {
+        let deps = setup();
+
+        // x coordinate without a point on the curve
+        let mut public_key = hex::decode(COMPRESSED_PUBKEY_HEX).unwrap();
+        public_key[1..].copy_from_slice(&[0xff; 32]);
+        let msg = QueryMsg::DecompressPubkey {
+            public_key: public_key.into(),
+        };
+        match query(&deps, msg).unwrap_err() {
+            StdError::GenericErr { msg, .. } => assert_eq!(msg, "Invalid public key format"),
+            err => panic!("Unexpected error: {:?}", err),
+        }
+
+        let msg = QueryMsg::CompressPubkey {
+            public_key: Binary(hex::decode(UNCOMPRESSED_PUBKEY_HEX).unwrap()[..64].to_vec()),
+        };
+        match query(&deps, msg).unwrap_err() {
+            StdError::GenericErr { msg, .. } => assert_eq!(msg, "Invalid public key format"),
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn derive_cosmos_address_works() {
+        let deps = setup();
+
+        // Test data from https://github.com/cosmos/cosmjs/blob/v0.24.0/packages/amino/src/addresses.spec.ts
+        let compressed =
+            Binary::from_base64("AtQaCqFnshaZQp6rIkvAPyzThvCvXSDO+9AzbxVErqJP").unwrap();
+        let msg = QueryMsg::DecompressPubkey {
+            public_key: compressed.clone(),
+        };
+        let uncompressed: PubkeyResponse = from_binary(&query(&deps, msg).unwrap()).unwrap();
+
+        for public_key in [compressed, uncompressed.public_key].iter() {
+            let msg = QueryMsg::DeriveCosmosAddress {
+                public_key: public_key.clone(),
+                prefix: "cosmos".to_string(),
+            };
+            let res: AddressResponse = from_binary(&query(&deps, msg).unwrap()).unwrap();
+            assert_eq!(res.address, "cosmos1h806c7khnvmjlywdrkdgk2vrayy2mmvf9rxk2r");
+        }
+
+        let msg = QueryMsg::DeriveCosmosAddress {
+            public_key: Binary::from_base64("AtQaCqFnshaZQp6rIkvAPyzThvCvXSDO+9AzbxVErqJP")
+                .unwrap(),
+            prefix: "".to_string(),
+        };
+        assert!(query(&deps, msg).is_err());
+    }
+
+    #[test]
+    fn derive_ethereum_address_works() {
+        let deps = setup();
+
+        // Test data from https://github.com/ethereumjs/ethereumjs-util/blob/v6.1.0/test/index.js#L404-L413
+        for public_key in [UNCOMPRESSED_PUBKEY_HEX, COMPRESSED_PUBKEY_HEX].iter() {
+            let msg = QueryMsg::DeriveEthereumAddress {
+                public_key: Binary(hex::decode(public_key).unwrap()),
+            };
+            let res: AddressResponse = from_binary(&query(&deps, msg).unwrap()).unwrap();
+            assert_eq!(res.address, "0x2F015C60E0be116B1f0CD534704Db9c92118FB6A");
+        }
+    }
+
+    #[test]
+    fn validate_pubkey_works() {
+        let deps = setup();
+
+        let mut off_curve = hex::decode(UNCOMPRESSED_PUBKEY_HEX).unwrap();
+        off_curve[64] ^= 1;
+        let cases = [
+            (hex::decode(UNCOMPRESSED_PUBKEY_HEX).unwrap(), true),
+            (hex::decode(COMPRESSED_PUBKEY_HEX).unwrap(), true),
+            (off_curve, false),
+            (
+                hex::decode(COMPRESSED_PUBKEY_HEX).unwrap()[..32].to_vec(),
+                false,
+            ),
+            (vec![], false),
+        ];
+        for (public_key, valid) in cases.iter() {
+            let msg = QueryMsg::ValidatePubkey {
+                public_key: Binary(public_key.clone()),
+            };
+            let res: ValidatePubkeyResponse = from_binary(&query(&deps, msg).unwrap()).unwrap();
+            assert_eq!(res.valid, *valid, "public key {}", hex::encode(public_key));
+        }
+    }
+
+    #[test]
+    fn ethereum_signature_verify_works() {
+        let deps = setup();
+
+        let message = ETHEREUM_MESSAGE;
+        let signature = hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap();
+        let signer_address = ETHEREUM_SIGNER_ADDRESS;
+
+        let verify_msg = QueryMsg::VerifyEthereumText {
+            message: message.into(),
+            signature: signature.into(),
+            signer_address: signer_address.into(),
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+
+        // address is matched case insensitive
+        let verify_msg = QueryMsg::VerifyEthereumText {
+            message: message.into(),
+            signature: hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap().into(),
+            signer_address: signer_address.to_lowercase(),
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn ethereum_signature_verify_fails_for_corrupted_message() {
+        let deps = setup();
+
+        let mut message = String::from(ETHEREUM_MESSAGE);
+        message.push('!');
+        let signature = hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap();
+        let signer_address = ETHEREUM_SIGNER_ADDRESS;
+
+        let verify_msg = QueryMsg::VerifyEthereumText {
+            message,
+            signature: signature.into(),
+            signer_address: signer_address.into(),
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn ethereum_signature_verify_fails_for_corrupted_signature() {
+        let deps = setup();
+
+        let message = ETHEREUM_MESSAGE;
+        let signer_address = ETHEREUM_SIGNER_ADDRESS;
+
+        // Wrong signature
+        let mut signature = hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap();
+        signature[5] ^= 0x01;
+        let verify_msg = QueryMsg::VerifyEthereumText {
+            message: message.into(),
+            signature: signature.into(),
+            signer_address: signer_address.into(),
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+
+        // Broken signature
+        let signature = vec![0x1c; 65];
+        let verify_msg = QueryMsg::VerifyEthereumText {
+            message: message.into(),
+            signature: signature.into(),
+            signer_address: signer_address.into(),
+        };
+        assert_eq!(
+            failure_reason(&deps, verify_msg),
+            FailureReason {
+                code: 10,
+                message: "Generic error".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn ethereum_signature_verify_errors() {
+        let deps = setup();
+
+        // Replay protected v
+        let mut signature = hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap();
+        signature[64] = 0x2b;
+        let verify_msg = QueryMsg::VerifyEthereumText {
+            message: ETHEREUM_MESSAGE.into(),
+            signature: signature.into(),
+            signer_address: ETHEREUM_SIGNER_ADDRESS.into(),
+        };
+        assert_eq!(
+            failure_reason(&deps, verify_msg),
+            FailureReason {
+                code: 101,
+                message: "Values of v other than 27 and 28 not supported. Replay protection (EIP-155) cannot be used here.".to_string(),
+            }
+        );
+
+        // Invalid address
+        let verify_msg = QueryMsg::VerifyEthereumText {
+            message: ETHEREUM_MESSAGE.into(),
+            signature: hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap().into(),
+            signer_address: "0x12890D2cce".into(),
+        };
+        assert_eq!(failure_reason(&deps, verify_msg).code, 100);
+    }
+
+    #[test]
+    fn verify_ethereum_transaction_works() {
+        let deps = setup();
+
+        // curl -sS -X POST --data '{"jsonrpc":"2.0","method":"eth_getTransactionByHash","params":["0x3b87faa3410f33284124a6898fac1001673f0f7c3682d18f55bdff0031cce9ce"],"id":1}' -H "Content-type: application/json" https://rinkeby-light.eth.linkpool.io | jq .result
+        // {
+        //   "blockHash": "0x05ebd1bd99956537f49cfa1104682b3b3f9ff9249fa41a09931ce93368606c21",
+        //   "blockNumber": "0x37ef3e",
+        //   "from": "0x0a65766695a712af41b5cfecaad217b1a11cb22a",
+        //   "gas": "0x226c8",
+        //   "gasPrice": "0x3b9aca00",
+        //   "hash": "0x3b87faa3410f33284124a6898fac1001673f0f7c3682d18f55bdff0031cce9ce",
+        //   "input": "0x536561726368207478207465737420302e36353930383639313733393634333335",
+        //   "nonce": "0xe1",
+        //   "to": "0xe137f5264b6b528244e1643a2d570b37660b7f14",
+        //   "transactionIndex": "0xb",
+        //   "value": "0x53177c",
+        //   "v": "0x2b",
+        //   "r": "0xb9299dab50b3cddcaecd64b29bfbd5cd30fac1a1adea1b359a13c4e5171492a6",
+        //   "s": "0x573059c66d894684488f92e7ce1f91b158ca57b0235485625b576a3b98c480ac"
+        // }
+        let msg = rinkeby_transaction("0x0a65766695a712af41b5cfecaad217b1a11cb22a", 0x2b);
+        let raw = query(&deps, msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+
+        // checksummed sender
+        let msg = rinkeby_transaction("0x0A65766695A712Af41B5cfECAaD217B1a11CB22A", 0x2b);
+        let raw = query(&deps, msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn verify_ethereum_transaction_fails() {
+        let deps = setup();
+
+        // different sender
+        let msg = rinkeby_transaction("0x12890D2cce102216644c59daE5baed380d84830c", 0x2b);
+        let raw = query(&deps, msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+
+        // other recovery id
+        let msg = rinkeby_transaction("0x0a65766695a712af41b5cfecaad217b1a11cb22a", 0x2c);
+        let raw = query(&deps, msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn verify_ethereum_transaction_errors() {
+        let deps = setup();
+
+        // v for another chain
+        let msg = rinkeby_transaction("0x0a65766695a712af41b5cfecaad217b1a11cb22a", 0x25);
+        assert_eq!(
+            failure_reason(&deps, msg),
+            FailureReason {
+                code: 101,
+                message:
+                    "Invalid v 37 for chain id 4. Expected chain_id * 2 + 35 or chain_id * 2 + 36."
+                        .to_string(),
+            }
+        );
+
+        // invalid sender address
+        let msg = rinkeby_transaction("0x0a65766695a712af41b5cfecaad217b1a11cb2", 0x2b);
+        assert_eq!(failure_reason(&deps, msg).code, 100);
+
+        // invalid recipient address
+        let mut msg = rinkeby_transaction("0x0a65766695a712af41b5cfecaad217b1a11cb22a", 0x2b);
+        if let QueryMsg::VerifyEthereumTransaction { to, .. } = &mut msg {
+            to.truncate(10);
+        }
+        assert_eq!(failure_reason(&deps, msg).code, 100);
+    }
+
+    fn rinkeby_transaction(from: &str, v: u64) -> QueryMsg {
+        QueryMsg::VerifyEthereumTransaction {
+            from: from.into(),
+            to: "0xe137f5264b6b528244e1643a2d570b37660b7f14".into(),
+            nonce: 0xe1,
+            gas_limit: Uint128(0x226c8),
+            gas_price: Uint128(0x3b9aca00),
+            value: Uint128(0x53177c),
+            data: hex!("536561726368207478207465737420302e36353930383639313733393634333335")
+                .to_vec()
+                .into(),
+            // Rinkeby, see https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md#list-of-chain-ids
+            chain_id: 4,
+            r: hex!("b9299dab50b3cddcaecd64b29bfbd5cd30fac1a1adea1b359a13c4e5171492a6")
+                .to_vec()
+                .into(),
+            s: hex!("573059c66d894684488f92e7ce1f91b158ca57b0235485625b576a3b98c480ac")
+                .to_vec()
+                .into(),
+            v,
+        }
+    }
+
+    // RFC 6979, A.2.5 (P-256, SHA-256, message "sample")
+    const SECP256R1_MESSAGE: &[u8] = b"sample";
+    const SECP256R1_SIGNATURE_HEX: &str = "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8";
+    const SECP256R1_PUBLIC_KEY_HEX: &str = "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+    const SECP256R1_COMPRESSED_PUBLIC_KEY_HEX: &str =
+        "0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6";
+
+    fn query_secp256r1(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> StdResult<VerifyResponse> {
+        let verify_msg = QueryMsg::VerifySecp256r1Signature {
+            message: Binary(message.to_vec()),
+            signature: Binary(signature.to_vec()),
+            public_key: Binary(public_key.to_vec()),
+        };
+        query(deps, verify_msg).map(|raw| from_binary(&raw).unwrap())
+    }
+
+    #[test]
+    fn secp256r1_signature_verify_works() {
+        let deps = setup();
+        let signature = hex::decode(SECP256R1_SIGNATURE_HEX).unwrap();
+
+        for public_key in &[
+            SECP256R1_PUBLIC_KEY_HEX,
+            SECP256R1_COMPRESSED_PUBLIC_KEY_HEX,
+        ] {
+            let public_key = hex::decode(public_key).unwrap();
+            let res = query_secp256r1(&deps, SECP256R1_MESSAGE, &signature, &public_key).unwrap();
+            assert_eq!(
+                res,
+                VerifyResponse {
+                    verifies: true,
+                    failure_reason: None
+                }
+            );
+        }
+
+        // The vector's `s` is high; its low-s counterpart `n - s` verifies as well
+        let mut low_s = signature[..32].to_vec();
+        low_s.extend(&hex!(
+            "0834e36ad29a83bf2bc9385e491d6099c8fdf9d1ed67aa7ea5f51f93782857a9"
+        ));
+        let public_key = hex::decode(SECP256R1_PUBLIC_KEY_HEX).unwrap();
+        let res = query_secp256r1(&deps, SECP256R1_MESSAGE, &low_s, &public_key).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn secp256r1_signature_verify_random_keys() {
+        let deps = setup();
+        let message = b"passkey owner";
+
+        let signing_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let signature: p256::ecdsa::Signature = signing_key.sign(message);
+        let public_key = p256::EncodedPoint::from(&signing_key.verify_key());
+
+        let res = query_secp256r1(&deps, message, signature.as_ref(), public_key.as_bytes());
+        assert_eq!(
+            res.unwrap(),
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+
+        let public_key = public_key.compress();
+        let res = query_secp256r1(&deps, message, signature.as_ref(), public_key.as_bytes());
+        assert_eq!(
+            res.unwrap(),
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn secp256r1_signature_verify_fails() {
+        let deps = setup();
+        let signature = hex::decode(SECP256R1_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(SECP256R1_PUBLIC_KEY_HEX).unwrap();
+
+        let res = query_secp256r1(&deps, b"test", &signature, &public_key).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+
+        // other key
+        let other_key = p256::ecdsa::SigningKey::random(&mut OsRng).verify_key();
+        let public_key = p256::EncodedPoint::from(&other_key);
+        let res = query_secp256r1(&deps, SECP256R1_MESSAGE, &signature, public_key.as_bytes());
+        assert_eq!(
+            res.unwrap(),
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn secp256r1_signature_verify_errors() {
+        let deps = setup();
+        let signature = hex::decode(SECP256R1_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(SECP256R1_PUBLIC_KEY_HEX).unwrap();
+
+        let expect_error = |res: StdResult<VerifyResponse>, code: u32, message: &str| {
+            assert_eq!(
+                res.unwrap(),
+                VerifyResponse {
+                    verifies: false,
+                    failure_reason: Some(FailureReason {
+                        code,
+                        message: message.to_string(),
+                    }),
+                }
+            )
+        };
+
+        expect_error(
+            query_secp256r1(&deps, SECP256R1_MESSAGE, &signature[1..], &public_key),
+            4,
+            "Invalid signature format",
+        );
+        // r = 0
+        let mut zero_r = vec![0u8; 32];
+        zero_r.extend(&signature[32..]);
+        expect_error(
+            query_secp256r1(&deps, SECP256R1_MESSAGE, &zero_r, &public_key),
+            4,
+            "Invalid signature format",
+        );
+        // s = 0
+        let mut zero_s = signature[..32].to_vec();
+        zero_s.extend(&[0u8; 32]);
+        expect_error(
+            query_secp256r1(&deps, SECP256R1_MESSAGE, &zero_s, &public_key),
+            4,
+            "Invalid signature format",
+        );
+        // s = n
+        let mut s_order = signature[..32].to_vec();
+        s_order.extend(&hex!(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+        ));
+        expect_error(
+            query_secp256r1(&deps, SECP256R1_MESSAGE, &s_order, &public_key),
+            4,
+            "Invalid signature format",
+        );
+        // x-only key
+        expect_error(
+            query_secp256r1(&deps, SECP256R1_MESSAGE, &signature, &public_key[1..33]),
+            5,
+            "Invalid public key format",
+        );
+        // not on the curve
+        let mut off_curve = public_key.clone();
+        off_curve[64] ^= 0x01;
+        expect_error(
+            query_secp256r1(&deps, SECP256R1_MESSAGE, &signature, &off_curve),
+            5,
+            "Invalid public key format",
+        );
+    }
+
+    const WEBAUTHN_RP_ID: &str = "gateway.axelar.network";
+    const WEBAUTHN_ORIGIN: &str = "https://gateway.axelar.network";
+    const WEBAUTHN_CHALLENGE: [u8; 32] = [0xab; 32];
+
+    /// Builds authenticator data for `rp_id` with the given flags and a sign count of 7
+    fn webauthn_authenticator_data(rp_id: &str, flags: u8) -> Vec<u8> {
+        let mut authenticator_data = Sha256::digest(rp_id.as_bytes()).to_vec();
+        authenticator_data.push(flags);
+        authenticator_data.extend_from_slice(&7u32.to_be_bytes());
+        authenticator_data
+    }
+
+    fn webauthn_client_data(ty: &str, challenge: &[u8], origin: &str) -> Vec<u8> {
+        format!(
+            r#"{{"type":"{}","challenge":"{}","origin":"{}","crossOrigin":false}}"#,
+            ty,
+            base64::encode_config(challenge, base64::URL_SAFE_NO_PAD),
+            origin
+        )
+        .into_bytes()
+    }
+
+    /// Signs an assertion like an authenticator does. Returns the DER signature.
+    fn webauthn_sign(
+        signing_key: &p256::ecdsa::SigningKey,
+        authenticator_data: &[u8],
+        client_data_json: &[u8],
+    ) -> Vec<u8> {
+        let mut signed = authenticator_data.to_vec();
+        signed.extend_from_slice(&Sha256::digest(client_data_json));
+        let signature: p256::ecdsa::Signature = signing_key.sign(&signed);
+        signature.to_asn1().as_bytes().to_vec()
+    }
+
+    fn webauthn_query(
+        authenticator_data: &[u8],
+        client_data_json: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+        require_user_verification: bool,
+    ) -> QueryMsg {
+        QueryMsg::VerifyWebauthnAssertion {
+            authenticator_data: Binary(authenticator_data.to_vec()),
+            client_data_json: Binary(client_data_json.to_vec()),
+            signature: Binary(signature.to_vec()),
+            public_key: Binary(public_key.to_vec()),
+            challenge: Binary(WEBAUTHN_CHALLENGE.to_vec()),
+            rp_id: WEBAUTHN_RP_ID.to_string(),
+            origin: Some(WEBAUTHN_ORIGIN.to_string()),
+            require_user_verification,
+        }
+    }
+
+    fn webauthn_verifies(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        query_msg: QueryMsg,
+    ) -> bool {
+        let raw = query(deps, query_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        res.verifies
+    }
+
+    #[test]
+    fn webauthn_assertion_verify_works() {
+        let deps = setup();
+        let signing_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let public_key = p256::EncodedPoint::from(&signing_key.verify_key());
+
+        let authenticator_data = webauthn_authenticator_data(WEBAUTHN_RP_ID, 0x05);
+        let client_data_json =
+            webauthn_client_data("webauthn.get", &WEBAUTHN_CHALLENGE, WEBAUTHN_ORIGIN);
+        let signature = webauthn_sign(&signing_key, &authenticator_data, &client_data_json);
+
+        for require_user_verification in &[false, true] {
+            let query_msg = webauthn_query(
+                &authenticator_data,
+                &client_data_json,
+                &signature,
+                public_key.as_bytes(),
+                *require_user_verification,
+            );
+            assert!(webauthn_verifies(&deps, query_msg));
+        }
+
+        // origin not checked
+        let mut query_msg = webauthn_query(
+            &authenticator_data,
+            &client_data_json,
+            &signature,
+            public_key.compress().as_bytes(),
+            false,
+        );
+        if let QueryMsg::VerifyWebauthnAssertion { origin, .. } = &mut query_msg {
+            *origin = None;
+        }
+        assert!(webauthn_verifies(&deps, query_msg));
+    }
+
+    #[test]
+    fn webauthn_assertion_verify_fails() {
+        let deps = setup();
+        let signing_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let public_key = p256::EncodedPoint::from(&signing_key.verify_key());
+
+        let valid_authenticator_data = webauthn_authenticator_data(WEBAUTHN_RP_ID, 0x05);
+        let valid_client_data_json =
+            webauthn_client_data("webauthn.get", &WEBAUTHN_CHALLENGE, WEBAUTHN_ORIGIN);
+
+        let cases = [
+            // user not verified
+            (
+                webauthn_authenticator_data(WEBAUTHN_RP_ID, 0x01),
+                valid_client_data_json.clone(),
+            ),
+            // user not present
+            (
+                webauthn_authenticator_data(WEBAUTHN_RP_ID, 0x04),
+                valid_client_data_json.clone(),
+            ),
+            // other relying party
+            (
+                webauthn_authenticator_data("axelar.network", 0x05),
+                valid_client_data_json.clone(),
+            ),
+            // other challenge
+            (
+                valid_authenticator_data.clone(),
+                webauthn_client_data("webauthn.get", &[0xac; 32], WEBAUTHN_ORIGIN),
+            ),
+            // other origin
+            (
+                valid_authenticator_data.clone(),
+                webauthn_client_data(
+                    "webauthn.get",
+                    &WEBAUTHN_CHALLENGE,
+                    "https://evil.example.com",
+                ),
+            ),
+            // registration instead of assertion
+            (
+                valid_authenticator_data.clone(),
+                webauthn_client_data("webauthn.create", &WEBAUTHN_CHALLENGE, WEBAUTHN_ORIGIN),
+            ),
+        ];
+        for (index, (authenticator_data, client_data_json)) in cases.iter().enumerate() {
+            let signature = webauthn_sign(&signing_key, authenticator_data, client_data_json);
+            let query_msg = webauthn_query(
+                authenticator_data,
+                client_data_json,
+                &signature,
+                public_key.as_bytes(),
+                true,
+            );
+            assert!(!webauthn_verifies(&deps, query_msg), "case {}", index);
+        }
+
+        // signed by another key
+        let other_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let signature = webauthn_sign(
+            &other_key,
+            &valid_authenticator_data,
+            &valid_client_data_json,
+        );
+        let query_msg = webauthn_query(
+            &valid_authenticator_data,
+            &valid_client_data_json,
+            &signature,
+            public_key.as_bytes(),
+            true,
+        );
+        assert!(!webauthn_verifies(&deps, query_msg));
+
+        // sign count changed after signing
+        let signature = webauthn_sign(
+            &signing_key,
+            &valid_authenticator_data,
+            &valid_client_data_json,
+        );
+        let mut authenticator_data = valid_authenticator_data.clone();
+        authenticator_data[36] += 1;
+        let query_msg = webauthn_query(
+            &authenticator_data,
+            &valid_client_data_json,
+            &signature,
+            public_key.as_bytes(),
+            true,
+        );
+        assert!(!webauthn_verifies(&deps, query_msg));
+    }
+
+    #[test]
+    fn webauthn_assertion_verify_errors() {
+        let deps = setup();
+        let signing_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let public_key = p256::EncodedPoint::from(&signing_key.verify_key());
+
+        let authenticator_data = webauthn_authenticator_data(WEBAUTHN_RP_ID, 0x05);
+        let client_data_json =
+            webauthn_client_data("webauthn.get", &WEBAUTHN_CHALLENGE, WEBAUTHN_ORIGIN);
+        let signature = webauthn_sign(&signing_key, &authenticator_data, &client_data_json);
+
+        let expect_error = |query_msg: QueryMsg, code: u32, expected: &str| {
+            let reason = failure_reason(&deps, query_msg);
+            assert_eq!(reason.code, code);
+            assert!(
+                reason.message.starts_with(expected),
+                "expected {:?}, got {:?}",
+                expected,
+                reason.message
+            );
+        };
+
+        expect_error(
+            webauthn_query(
+                &authenticator_data[..36],
+                &client_data_json,
+                &signature,
+                public_key.as_bytes(),
+                false,
+            ),
+            102,
+            "Authenticator data must be at least 37 bytes long, got 36",
+        );
+        expect_error(
+            webauthn_query(
+                &authenticator_data,
+                br#"{"type":"webauthn.get","origin":"https://gateway.axelar.network"}"#,
+                &signature,
+                public_key.as_bytes(),
+                false,
+            ),
+            102,
+            "Invalid clientDataJSON: ",
+        );
+        expect_error(
+            webauthn_query(
+                &authenticator_data,
+                &client_data_json[1..],
+                &signature,
+                public_key.as_bytes(),
+                false,
+            ),
+            102,
+            "Invalid clientDataJSON: ",
+        );
+        // fixed length instead of DER signature
+        let fixed = p256::ecdsa::Signature::from_asn1(&signature).unwrap();
+        expect_error(
+            webauthn_query(
+                &authenticator_data,
+                &client_data_json,
+                fixed.as_ref(),
+                public_key.as_bytes(),
+                false,
+            ),
+            4,
+            "Invalid signature format",
+        );
+        expect_error(
+            webauthn_query(
+                &authenticator_data,
+                &client_data_json,
+                &signature,
+                &public_key.as_bytes()[1..],
+                false,
+            ),
+            5,
+            "Invalid public key format",
+        );
+    }
+
+    // Test vectors from https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+    // (index, public key, message, signature, result). `None` marks public keys that are rejected
+    // with an error ("public key not on the curve" / "public key exceeds field size").
+    const BIP340_VECTORS: &[(u32, &str, &str, &str, Option<bool>)] = &[
+        (0, "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9", "0000000000000000000000000000000000000000000000000000000000000000", "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0", Some(true)),
+        (1, "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A", Some(true)),
+        (2, "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8", "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C", "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1BAB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7", Some(true)),
+        (3, "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517", "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3", Some(true)),
+        (4, "D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9", "4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703", "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C6376AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4", Some(true)),
+        (5, "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B", None),
+        (6, "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A14602975563CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2", Some(false)),
+        (7, "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "1FA62E331EDBC21C394792D2AB1100A7B432B013DF3F6FF4F99FCB33E0E1515F28890B3EDB6E7189B630448B515CE4F8622A954CFE545735AAEA5134FCCDB2BD", Some(false)),
+        (8, "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769961764B3AA9B2FFCB6EF947B6887A226E8D7C93E00C5ED0C1834FF0D0C2E6DA6", Some(false)),
+        (9, "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "0000000000000000000000000000000000000000000000000000000000000000123DDA8328AF9C23A94C1FEECFD123BA4FB73476F0D594DCB65C6425BD186051", Some(false)),
+        (10, "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "00000000000000000000000000000000000000000000000000000000000000017615FBAF5AE28864013C099742DEADB4DBA87F11AC6754F93780D5A1837CF197", Some(false)),
+        (11, "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B", Some(false)),
+        (12, "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B", Some(false)),
+        (13, "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141", Some(false)),
+        (14, "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30", "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89", "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B", None),
+        (15, "778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117", "", "71535DB165ECD9FBBC046E5FFAEA61186BB6AD436732FCCC25291A55895464CF6069CE26BF03466228F19A3A62DB8A649F2D560FAC652827D1AF0574E427AB63", Some(true)),
+        (16, "778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117", "11", "08A20A0AFEF64124649232E0693C583AB1B9934AE63B4C3511F3AE1134C6A303EA3173BFEA6683BD101FA5AA5DBC1996FE7CACFC5A577D33EC14564CEC2BACBF", Some(true)),
+        (17, "778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117", "0102030405060708090A0B0C0D0E0F1011", "5130F39A4059B43BC7CAC09A19ECE52B5D8699D1A71E3C52DA9AFDB6B50AC370C4A482B77BF960F8681540E25B6771ECE1E5A37FD80E5A51897C5566A97EA5A5", Some(true)),
+        (18, "778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117", "99999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999", "403B12B0D8555A344175EA7EC746566303321E5DBFA8BE6F091635163ECA79A8585ED3E3170807E7C03B720FC54C7B23897FCBA0E9D0B4A06894CFD249F22367", Some(true)),
+    ];
+
+    #[test]
+    fn schnorr_signature_verify_bip340_vectors() {
+        let deps = setup();
+
+        for (index, public_key, message, signature, expected) in BIP340_VECTORS {
+            let verify_msg = QueryMsg::VerifySchnorrSignature {
+                message: Binary(hex::decode(message).unwrap()),
+                signature: Binary(hex::decode(signature).unwrap()),
+                public_key: Binary(hex::decode(public_key).unwrap()),
+            };
+            let res: VerifyResponse = from_binary(&query(&deps, verify_msg).unwrap()).unwrap();
+            match expected {
+                Some(expected) => {
+                    assert_eq!(res.verifies, *expected, "vector {}", index);
+                    assert_eq!(res.failure_reason, None, "vector {}", index);
+                }
+                None => assert_eq!(
+                    res,
+                    VerifyResponse {
+                        verifies: false,
+                        failure_reason: Some(FailureReason {
+                            code: 5,
+                            message: "Invalid public key format".to_string(),
+                        }),
+                    },
+                    "vector {}",
+                    index
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn schnorr_signature_verify_errors() {
+        let deps = setup();
+        let (_, public_key, message, signature, _) = BIP340_VECTORS[1];
+
+        let verify_msg = QueryMsg::VerifySchnorrSignature {
+            message: Binary(hex::decode(message).unwrap()),
+            signature: Binary(hex::decode(&signature[2..]).unwrap()),
+            public_key: Binary(hex::decode(public_key).unwrap()),
+        };
+        assert_eq!(
+            failure_reason(&deps, verify_msg),
+            FailureReason {
+                code: 4,
+                message: "Invalid signature format".to_string(),
+            }
+        );
+
+        // compressed SEC1 keys are not x-only keys
+        let mut compressed = vec![0x02];
+        compressed.extend(hex::decode(public_key).unwrap());
+        let verify_msg = QueryMsg::VerifySchnorrSignature {
+            message: Binary(hex::decode(message).unwrap()),
+            signature: Binary(hex::decode(signature).unwrap()),
+            public_key: Binary(compressed),
+        };
+        assert_eq!(
+            failure_reason(&deps, verify_msg),
+            FailureReason {
+                code: 5,
+                message: "Invalid public key format".to_string(),
+            }
+        );
+    }
+
+    // Ethereum consensus spec BLS `sign` test vector (keys in G1, signature in G2)
+    const BLS_PUBLIC_KEY_HEX: &str = "b301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81";
+    const BLS_MESSAGE_HEX: &str =
+        "5656565656565656565656565656565656565656565656565656565656565656";
+    const BLS_SIGNATURE_HEX: &str = "af1390c3c47acdb37131a51216da683c509fce0e954328a59f93aebda7e4ff974ba208d9a4a2a2389f892a9d418d618418dd7f7a6bc7aa0da999a9d3a5b815bc085e14fd001f6a1948768a3f4afefc8b8240dda329f984cb345c6363272ba4fe";
+
+    /// Signs with public key in G1 and signature in G2. Returns (public key, signature).
+    fn bls_sign_g2(secret: u64, message: &[u8], dst: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let secret = Scalar::from(secret);
+        let public_key = G1Affine::from(G1Affine::generator() * secret);
+        let hash = <G2Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(message, dst);
+        let signature = G2Affine::from(hash * secret);
+        (
+            public_key.to_compressed().to_vec(),
+            signature.to_compressed().to_vec(),
+        )
+    }
+
+    /// Signs with public key in G2 and signature in G1. Returns (public key, signature).
+    fn bls_sign_g1(secret: u64, message: &[u8], dst: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let secret = Scalar::from(secret);
+        let public_key = G2Affine::from(G2Affine::generator() * secret);
+        let signature = G1Affine::from(hash_to_g1(message, dst) * secret);
+        (
+            public_key.to_compressed().to_vec(),
+            signature.to_compressed().to_vec(),
+        )
+    }
+
+    /// Adds compressed G2 signatures
+    fn bls_aggregate_g2(signatures: &[Vec<u8>]) -> Vec<u8> {
+        let sum: G2Projective = signatures
+            .iter()
+            .map(|s| {
+                G2Projective::from(G2Affine::from_compressed(s[..].try_into().unwrap()).unwrap())
+            })
+            .sum();
+        G2Affine::from(sum).to_compressed().to_vec()
+    }
+
+    /// Adds compressed G1 signatures
+    fn bls_aggregate_g1(signatures: &[Vec<u8>]) -> Vec<u8> {
+        let sum: G1Projective = signatures
+            .iter()
+            .map(|s| {
+                G1Projective::from(G1Affine::from_compressed(s[..].try_into().unwrap()).unwrap())
+            })
+            .sum();
+        G1Affine::from(sum).to_compressed().to_vec()
+    }
+
+    fn query_bls_aggregate(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        messages: &[&[u8]],
+        signature: &[u8],
+        public_keys: &[&Vec<u8>],
+    ) -> StdResult<VerifyResponse> {
+        let verify_msg = QueryMsg::VerifyBlsAggregate {
+            messages: messages.iter().map(|m| Binary(m.to_vec())).collect(),
+            signature: Binary(signature.to_vec()),
+            public_keys: public_keys.iter().map(|k| Binary(k.to_vec())).collect(),
+            dst: None,
+        };
+        query(deps, verify_msg).map(|raw| from_binary(&raw).unwrap())
+    }
+
+    #[test]
+    fn bls_signature_verify_works() {
+        let deps = setup();
+
+        let verify_msg = QueryMsg::VerifyBlsSignature {
+            message: Binary(hex::decode(BLS_MESSAGE_HEX).unwrap()),
+            signature: Binary(hex::decode(BLS_SIGNATURE_HEX).unwrap()),
+            public_key: Binary(hex::decode(BLS_PUBLIC_KEY_HEX).unwrap()),
+            dst: None,
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+
+        // explicit default dst
+        let verify_msg = QueryMsg::VerifyBlsSignature {
+            message: Binary(hex::decode(BLS_MESSAGE_HEX).unwrap()),
+            signature: Binary(hex::decode(BLS_SIGNATURE_HEX).unwrap()),
+            public_key: Binary(hex::decode(BLS_PUBLIC_KEY_HEX).unwrap()),
+            dst: Some(Binary(DST_G2_SIGNATURES.to_vec())),
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn bls_signature_verify_fails() {
+        let deps = setup();
+
+        let mut message = hex::decode(BLS_MESSAGE_HEX).unwrap();
+        message[0] ^= 0x01;
+        let verify_msg = QueryMsg::VerifyBlsSignature {
+            message: Binary(message),
+            signature: Binary(hex::decode(BLS_SIGNATURE_HEX).unwrap()),
+            public_key: Binary(hex::decode(BLS_PUBLIC_KEY_HEX).unwrap()),
+            dst: None,
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+
+        // other dst
+        let verify_msg = QueryMsg::VerifyBlsSignature {
+            message: Binary(hex::decode(BLS_MESSAGE_HEX).unwrap()),
+            signature: Binary(hex::decode(BLS_SIGNATURE_HEX).unwrap()),
+            public_key: Binary(hex::decode(BLS_PUBLIC_KEY_HEX).unwrap()),
+            dst: Some(Binary(
+                b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_".to_vec(),
+            )),
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn bls_signature_verify_g1_signatures_works() {
+        let deps = setup();
+
+        let message = b"axelar";
+        let (public_key, signature) = bls_sign_g1(0x5eed, message, DST_G1_SIGNATURES);
+        assert_eq!(public_key.len(), 96);
+        assert_eq!(signature.len(), 48);
+
+        let verify_msg = QueryMsg::VerifyBlsSignature {
+            message: Binary(message.to_vec()),
+            signature: Binary(signature.clone()),
+            public_key: Binary(public_key.clone()),
+            dst: None,
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+
+        // signed with the G2 signature dst
+        let (public_key, signature) = bls_sign_g1(0x5eed, message, DST_G2_SIGNATURES);
+        let verify_msg = QueryMsg::VerifyBlsSignature {
+            message: Binary(message.to_vec()),
+            signature: Binary(signature.clone()),
+            public_key: Binary(public_key.clone()),
+            dst: None,
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
+
+        let verify_msg = QueryMsg::VerifyBlsSignature {
+            message: Binary(message.to_vec()),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
+            dst: Some(Binary(DST_G2_SIGNATURES.to_vec())),
+        };
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn bls_aggregate_verify_same_message_works() {
+        let deps = setup();
+        let message: &[u8] = b"same message";
+
+        // G2 signatures
+        let signed: Vec<_> = [11u64, 22, 33]
+            .iter()
+            .map(|secret| bls_sign_g2(*secret, message, DST_G2_SIGNATURES))
+            .collect();
+        let public_keys: Vec<_> = signed.iter().map(|(pk, _)| pk).collect();
+        let signatures: Vec<_> = signed.iter().map(|(_, sig)| sig.clone()).collect();
+        let aggregate = bls_aggregate_g2(&signatures);
+
+        let res = query_bls_aggregate(&deps, &[message], &aggregate, &public_keys).unwrap();
+        assert!(res.verifies);
+        let res = query_bls_aggregate(&deps, &[message], &aggregate, &public_keys[..2]).unwrap();
+        assert!(!res.verifies);
+        let res = query_bls_aggregate(&deps, &[b"other"], &aggregate, &public_keys).unwrap();
+        assert!(!res.verifies);
+
+        // G1 signatures
+        let signed: Vec<_> = [11u64, 22, 33]
+            .iter()
+            .map(|secret| bls_sign_g1(*secret, message, DST_G1_SIGNATURES))
+            .collect();
+        let public_keys: Vec<_> = signed.iter().map(|(pk, _)| pk).collect();
+        let signatures: Vec<_> = signed.iter().map(|(_, sig)| sig.clone()).collect();
+        let aggregate = bls_aggregate_g1(&signatures);
+
+        let res = query_bls_aggregate(&deps, &[message], &aggregate, &public_keys).unwrap();
+        assert!(res.verifies);
+        let res = query_bls_aggregate(&deps, &[message], &aggregate, &public_keys[1..]).unwrap();
+        assert!(!res.verifies);
+    }
+
+    #[test]
+    fn bls_aggregate_verify_distinct_messages_works() {
+        let deps = setup();
+        let messages: [&[u8]; 3] = [b"first", b"second", b"third"];
+
+        // G2 signatures
+        let signed: Vec<_> = messages
+            .iter()
+            .zip([11u64, 22, 33].iter())
+            .map(|(message, secret)| bls_sign_g2(*secret, message, DST_G2_SIGNATURES))
+            .collect();
+        let public_keys: Vec<_> = signed.iter().map(|(pk, _)| pk).collect();
+        let signatures: Vec<_> = signed.iter().map(|(_, sig)| sig.clone()).collect();
+        let aggregate = bls_aggregate_g2(&signatures);
+
+        let res = query_bls_aggregate(&deps, &messages, &aggregate, &public_keys).unwrap();
+        assert!(res.verifies);
+        let swapped = [messages[1], messages[0], messages[2]];
+        let res = query_bls_aggregate(&deps, &swapped, &aggregate, &public_keys).unwrap();
+        assert!(!res.verifies);
+
+        // G1 signatures
+        let signed: Vec<_> = messages
+            .iter()
+            .zip([11u64, 22, 33].iter())
+            .map(|(message, secret)| bls_sign_g1(*secret, message, DST_G1_SIGNATURES))
+            .collect();
+        let public_keys: Vec<_> = signed.iter().map(|(pk, _)| pk).collect();
+        let signatures: Vec<_> = signed.iter().map(|(_, sig)| sig.clone()).collect();
+        let aggregate = bls_aggregate_g1(&signatures);
+
+        let res = query_bls_aggregate(&deps, &messages, &aggregate, &public_keys).unwrap();
+        assert!(res.verifies);
+        let res = query_bls_aggregate(&deps, &swapped, &aggregate, &public_keys).unwrap();
+        assert!(!res.verifies);
+    }
+
+    #[test]
+    fn bls_verify_errors() {
+        let deps = setup();
+        let message = hex::decode(BLS_MESSAGE_HEX).unwrap();
+        let signature = hex::decode(BLS_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(BLS_PUBLIC_KEY_HEX).unwrap();
+
+        let expect_error = |res: StdResult<VerifyResponse>, code: u32, message: &str| {
+            assert_eq!(
+                res.unwrap(),
+                VerifyResponse {
+                    verifies: false,
+                    failure_reason: Some(FailureReason {
+                        code,
+                        message: message.to_string(),
+                    }),
+                }
+            )
+        };
+
+        // invalid signature length
+        expect_error(
+            query_bls_aggregate(&deps, &[&message], &signature[1..], &[&public_key]),
+            4,
+            "Invalid signature format",
+        );
+        // not a curve point
+        let mut bad_signature = signature.clone();
+        bad_signature[95] ^= 0x01;
+        expect_error(
+            query_bls_aggregate(&deps, &[&message], &bad_signature, &[&public_key]),
+            4,
+            "Invalid signature format",
+        );
+        // G1 public key with G1 signature
+        let g1_signature = public_key.clone();
+        expect_error(
+            query_bls_aggregate(&deps, &[&message], &g1_signature, &[&public_key]),
+            5,
+            "Invalid public key format",
+        );
+        // identity public key
+        let mut identity = vec![0u8; 48];
+        identity[0] = 0xc0;
+        expect_error(
+            query_bls_aggregate(&deps, &[&message], &signature, &[&identity]),
+            5,
+            "Invalid public key format",
+        );
+        // no public keys
+        expect_error(
+            query_bls_aggregate(&deps, &[&message], &signature, &[]),
+            102,
+            "At least one public key is required",
+        );
+        // message count does not match
+        expect_error(
+            query_bls_aggregate(
+                &deps,
+                &[b"a", b"b"],
+                &signature,
+                &[&public_key, &public_key, &public_key],
+            ),
+            102,
+            "Expected one message, or one message per public key (3), got 2",
+        );
+        // repeated messages
+        expect_error(
+            query_bls_aggregate(
+                &deps,
+                &[b"a", b"a"],
+                &signature,
+                &[&public_key, &public_key],
+            ),
+            102,
+            "Aggregated messages must be distinct",
+        );
+    }
+
+    #[test]
+    fn tendermint_signature_verify_works() {
+        let deps = setup();
+
+        let message = hex::decode(ED25519_MESSAGE_HEX).unwrap();
+        let signature = hex::decode(ED25519_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap();
+
+        let verify_msg = QueryMsg::VerifyTendermintSignature {
+            message: Binary(message),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
+        };
+
+        let raw = query(&deps, verify_msg).unwrap();
+        let res: VerifyResponse = from_binary(&raw).unwrap();
+
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: true,
+                failure_reason: None
+            }
+        );
+    }
+
+    #[test]
+    fn tendermint_signature_verify_fails() {
+        let deps = setup();
+
+        let mut message = hex::decode(ED25519_MESSAGE_HEX).unwrap();
+        // alter message
+        message[0] ^= 0x01;
+        let signature = hex::decode(ED25519_SIGNATURE_HEX).unwrap();
+        let public_key = hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap();
+
+        let verify_msg = QueryMsg::VerifyTendermintSignature {
+            message: Binary(message),
+            signature: Binary(signature),
+            public_key: Binary(public_key),
         };
 
         let raw = query(&deps, verify_msg).unwrap();
         let res: VerifyResponse = from_binary(&raw).unwrap();
 
-        assert_eq!(res, VerifyResponse { verifies: false });
+        assert_eq!(
+            res,
+            VerifyResponse {
+                verifies: false,
+                failure_reason: None
+            }
+        );
     }
 
     #[test]
-    fn cosmos_signature_verify_errors() {
+    fn tendermint_signature_verify_errors() {
         let deps = setup();
 
-        let message = hex::decode(SECP256K1_MESSAGE_HEX).unwrap();
-        let signature = hex::decode(SECP256K1_SIGNATURE_HEX).unwrap();
+        let message = hex::decode(ED25519_MESSAGE_HEX).unwrap();
+        let signature = hex::decode(ED25519_SIGNATURE_HEX).unwrap();
         let public_key = vec![];
 
-        let verify_msg = QueryMsg::VerifyCosmosSignature {
-            message: Binary(message),
+        let verify_msg = QueryMsg::VerifyTendermintSignature {
+            message: Binary(message.clone()),
             signature: Binary(signature),
             public_key: Binary(public_key),
         };
+        assert_eq!(
+            failure_reason(&deps, verify_msg),
+            FailureReason {
+                code: 5,
+                message: "Invalid public key format".to_string(),
+            }
+        );
+
+        let verify_msg = QueryMsg::VerifyTendermintSignature {
+            message: Binary(message),
+            signature: Binary(vec![]),
+            public_key: Binary(hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap()),
+        };
+        assert_eq!(
+            failure_reason(&deps, verify_msg),
+            FailureReason {
+                code: 4,
+                message: "Invalid signature format".to_string(),
+            }
+        );
+    }
+
+    fn hex_binaries(items: &[&str]) -> Vec<Binary> {
+        items
+            .iter()
+            .map(|m| Binary(hex::decode(m).unwrap()))
+            .collect()
+    }
+
+    fn verify_tendermint_batch(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        messages: Vec<Binary>,
+        signatures: Vec<Binary>,
+        public_keys: Vec<Binary>,
+    ) -> StdResult<VerifyBatchResponse> {
+        let verify_msg = QueryMsg::VerifyTendermintBatch {
+            messages,
+            signatures,
+            public_keys,
+        };
+        query(deps, verify_msg).map(|raw| from_binary(&raw).unwrap())
+    }
+
+    #[test]
+    fn tendermint_signatures_batch_verify_works() {
+        let deps = setup();
+
+        let res = verify_tendermint_batch(
+            &deps,
+            hex_binaries(&[ED25519_MESSAGE_HEX, ED25519_MESSAGE2_HEX]),
+            hex_binaries(&[ED25519_SIGNATURE_HEX, ED25519_SIGNATURE2_HEX]),
+            hex_binaries(&[ED25519_PUBLIC_KEY_HEX, ED25519_PUBLIC_KEY2_HEX]),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            VerifyBatchResponse {
+                verifies: true,
+                failed_indices: vec![],
+                failure_reason: None,
+                entry_failures: vec![],
+            }
+        );
+
+        // empty batch
+        let res = verify_tendermint_batch(&deps, vec![], vec![], vec![]).unwrap();
+        assert!(res.verifies);
+    }
+
+    #[test]
+    fn tendermint_signatures_batch_verify_message_multisig_works() {
+        let deps = setup();
+
+        // One message, signed by several keys
+        let message = b"commit for height 42".to_vec();
+        let signing_keys: Vec<ed25519_zebra::SigningKey> = (0..3)
+            .map(|_| ed25519_zebra::SigningKey::new(OsRng))
+            .collect();
+        let signatures = signing_keys
+            .iter()
+            .map(|k| Binary::from(<[u8; 64]>::from(k.sign(&message)).as_ref()))
+            .collect();
+        let public_keys = signing_keys
+            .iter()
+            .map(|k| Binary::from(ed25519_zebra::VerificationKeyBytes::from(k).as_ref()))
+            .collect();
+
+        let res =
+            verify_tendermint_batch(&deps, vec![Binary(message)], signatures, public_keys).unwrap();
+        assert!(res.verifies);
+    }
+
+    #[test]
+    fn tendermint_signatures_batch_verify_single_public_key_works() {
+        let deps = setup();
+
+        // Multiple messages with the same signature and public key
+        //FIXME: Use different messages / signatures
+        let res = verify_tendermint_batch(
+            &deps,
+            hex_binaries(&[ED25519_MESSAGE_HEX, ED25519_MESSAGE_HEX]),
+            hex_binaries(&[ED25519_SIGNATURE_HEX, ED25519_SIGNATURE_HEX]),
+            hex_binaries(&[ED25519_PUBLIC_KEY_HEX]),
+        )
+        .unwrap();
+        assert!(res.verifies);
+    }
+
+    #[test]
+    fn tendermint_signatures_batch_verify_fails() {
+        let deps = setup();
+
+        let mut messages = hex_binaries(&[ED25519_MESSAGE_HEX, ED25519_MESSAGE2_HEX]);
+        // Alter one of the messages
+        messages[1].0[0] ^= 0x01;
+
+        let res = verify_tendermint_batch(
+            &deps,
+            messages,
+            hex_binaries(&[ED25519_SIGNATURE_HEX, ED25519_SIGNATURE2_HEX]),
+            hex_binaries(&[ED25519_PUBLIC_KEY_HEX, ED25519_PUBLIC_KEY2_HEX]),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            VerifyBatchResponse {
+                verifies: false,
+                failed_indices: vec![1],
+                failure_reason: None,
+                entry_failures: vec![],
+            }
+        );
+
+        // Swapped signatures fail both
+        let res = verify_tendermint_batch(
+            &deps,
+            hex_binaries(&[ED25519_MESSAGE_HEX, ED25519_MESSAGE2_HEX]),
+            hex_binaries(&[ED25519_SIGNATURE2_HEX, ED25519_SIGNATURE_HEX]),
+            hex_binaries(&[ED25519_PUBLIC_KEY_HEX, ED25519_PUBLIC_KEY2_HEX]),
+        )
+        .unwrap();
+        assert_eq!(res.failed_indices, vec![0, 1]);
+    }
+
+    #[test]
+    fn tendermint_signatures_batch_verify_malformed_entries() {
+        let deps = setup();
+
+        // A truncated signature and an empty public key only fail their own entries
+        let mut signatures = hex_binaries(&[
+            ED25519_SIGNATURE_HEX,
+            ED25519_SIGNATURE2_HEX,
+            ED25519_SIGNATURE_HEX,
+        ]);
+        signatures[1].0.pop();
+        let res = verify_tendermint_batch(
+            &deps,
+            hex_binaries(&[
+                ED25519_MESSAGE_HEX,
+                ED25519_MESSAGE2_HEX,
+                ED25519_MESSAGE_HEX,
+            ]),
+            signatures,
+            hex_binaries(&[ED25519_PUBLIC_KEY_HEX, ED25519_PUBLIC_KEY2_HEX, ""]),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            VerifyBatchResponse {
+                verifies: false,
+                failed_indices: vec![1, 2],
+                failure_reason: None,
+                entry_failures: vec![
+                    entry_failure(1, 4, "Invalid signature format"),
+                    entry_failure(2, 5, "Invalid public key format"),
+                ],
+            }
+        );
+
+        // Malformed and invalid entries are reported together, in order
+        let mut messages = hex_binaries(&[ED25519_MESSAGE_HEX, ED25519_MESSAGE2_HEX]);
+        messages[0].0[0] ^= 0x01;
+        let res = verify_tendermint_batch(
+            &deps,
+            messages,
+            hex_binaries(&[ED25519_SIGNATURE_HEX, ED25519_SIGNATURE2_HEX]),
+            hex_binaries(&[ED25519_PUBLIC_KEY_HEX, ""]),
+        )
+        .unwrap();
+        assert_eq!(res.failed_indices, vec![0, 1]);
+        assert_eq!(
+            res.entry_failures,
+            vec![entry_failure(1, 5, "Invalid public key format")]
+        );
+    }
+
+    #[test]
+    fn tendermint_signatures_batch_verify_errors() {
+        let deps = setup();
+
+        // Mismatched shapes
+        let res = verify_tendermint_batch(
+            &deps,
+            hex_binaries(&[ED25519_MESSAGE_HEX, ED25519_MESSAGE2_HEX]),
+            hex_binaries(&[ED25519_SIGNATURE_HEX]),
+            hex_binaries(&[ED25519_PUBLIC_KEY_HEX, ED25519_PUBLIC_KEY2_HEX]),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            VerifyBatchResponse {
+                verifies: false,
+                failed_indices: vec![],
+                failure_reason: Some(FailureReason {
+                    code: 7,
+                    message: "Batch error: Mismatched / erroneous number of messages (2) / signatures (1) / public keys (2)".to_string(),
+                }),
+                entry_failures: vec![],
+            }
+        );
+    }
+
+    #[derive(serde::Deserialize)]
+    struct ReceiptProofs {
+        receipts_root: String,
+        proofs: Vec<ReceiptProof>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct ReceiptProof {
+        tx_index: u64,
+        proof: Vec<String>,
+    }
+
+    fn receipt_proofs() -> (Vec<u8>, Vec<(u64, Vec<Binary>)>) {
+        let data: ReceiptProofs =
+            from_slice(include_bytes!("../testdata/receipt_proofs.json")).unwrap();
+        let proofs = data
+            .proofs
+            .into_iter()
+            .map(|entry| {
+                let proof = entry
+                    .proof
+                    .iter()
+                    .map(|node| Binary(hex::decode(node).unwrap()))
+                    .collect();
+                (entry.tx_index, proof)
+            })
+            .collect();
+        (hex::decode(data.receipts_root).unwrap(), proofs)
+    }
+
+    fn query_receipt_proof(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        receipts_root: &[u8],
+        tx_index: u64,
+        proof: Vec<Binary>,
+    ) -> StdResult<EthereumReceiptResponse> {
+        let query_msg = QueryMsg::VerifyEthereumReceiptProof {
+            receipts_root: Binary(receipts_root.to_vec()),
+            tx_index,
+            proof,
+        };
+        query(deps, query_msg).map(|raw| from_binary(&raw).unwrap())
+    }
+
+    #[test]
+    fn verify_ethereum_receipt_proof_works() {
+        let deps = setup();
+        let (receipts_root, proofs) = receipt_proofs();
+        let transfer_topic = Keccak256::digest(b"Transfer(address,address,uint256)").to_vec();
+
+        for (tx_index, proof) in proofs.into_iter().filter(|(i, _)| *i < 20) {
+            let res = query_receipt_proof(&deps, &receipts_root, tx_index, proof).unwrap();
+            assert!(res.verifies);
+            assert_eq!(res.failure_reason, None);
+            let res = res.receipt.unwrap();
+            // every third transaction is a legacy transaction, transaction 5 failed
+            assert_eq!(res.tx_type, if tx_index % 3 == 0 { 0 } else { 2 });
+            assert_eq!(res.success, tx_index != 5);
+            assert_eq!(
+                res.cumulative_gas_used,
+                Uint128(21_000 * (tx_index as u128 + 1) + 1234)
+            );
+
+            // even transactions transfer USDC
+            let transfers: Vec<_> = res
+                .logs
+                .iter()
+                .filter(|log| log.topics.first().map(|t| t.as_slice()) == Some(&transfer_topic))
+                .collect();
+            if tx_index % 2 == 0 {
+                assert_eq!(transfers.len(), 1);
+                assert_eq!(
+                    transfers[0].address,
+                    "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+                );
+                assert_eq!(transfers[0].topics.len(), 3);
+                let mut amount = [0u8; 32];
+                amount[24..].copy_from_slice(&(1_000_000 * (tx_index + 1)).to_be_bytes());
+                assert_eq!(transfers[0].data.as_slice(), amount);
+            } else {
+                assert!(transfers.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn verify_ethereum_receipt_proof_logs() {
+        let deps = setup();
+        let (receipts_root, proofs) = receipt_proofs();
+        let (_, proof) = proofs.into_iter().find(|(i, _)| *i == 17).unwrap();
+
+        let res = query_receipt_proof(&deps, &receipts_root, 17, proof)
+            .unwrap()
+            .receipt
+            .unwrap();
+        let bridge = "0x52908400098527886e0f7030069857d2e4169ee7";
+        assert_eq!(
+            res.logs,
+            vec![
+                EthereumLog {
+                    address: bridge.to_string(),
+                    topics: vec![
+                        Binary(Keccak256::digest(b"Deposit(bytes32)").to_vec()),
+                        Binary(vec![0x42; 32]),
+                    ],
+                    data: Binary(vec![]),
+                },
+                EthereumLog {
+                    address: bridge.to_string(),
+                    topics: vec![],
+                    data: Binary(b"axelar".to_vec()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_ethereum_receipt_proof_errors() {
+        let deps = setup();
+        let (receipts_root, proofs) = receipt_proofs();
+        let proof_of = |tx_index: u64| {
+            proofs
+                .iter()
+                .find(|(i, _)| *i == tx_index)
+                .map(|(_, proof)| proof.clone())
+                .unwrap()
+        };
+
+        let expect_error = |res: StdResult<EthereumReceiptResponse>, expected: &str| {
+            assert_eq!(
+                res.unwrap(),
+                EthereumReceiptResponse {
+                    verifies: false,
+                    failure_reason: Some(FailureReason {
+                        code: 103,
+                        message: expected.to_string(),
+                    }),
+                    receipt: None,
+                }
+            )
+        };
+
+        // proven absence
+        assert_eq!(
+            query_receipt_proof(&deps, &receipts_root, 25, proof_of(25)).unwrap(),
+            EthereumReceiptResponse {
+                verifies: false,
+                failure_reason: None,
+                receipt: None,
+            }
+        );
+        // other root
+        let mut other_root = receipts_root.clone();
+        other_root[0] ^= 0x01;
+        expect_error(
+            query_receipt_proof(&deps, &other_root, 1, proof_of(1)),
+            "Invalid Merkle-Patricia proof: node hash mismatch",
+        );
+        // proof of another receipt
+        expect_error(
+            query_receipt_proof(&deps, &receipts_root, 5, proof_of(1)),
+            "Invalid Merkle-Patricia proof: node hash mismatch",
+        );
+        // altered receipt
+        let mut proof = proof_of(1);
+        let leaf = proof.last_mut().unwrap();
+        let last = leaf.0.len() - 1;
+        leaf.0[last] ^= 0x01;
+        expect_error(
+            query_receipt_proof(&deps, &receipts_root, 1, proof),
+            "Invalid Merkle-Patricia proof: node hash mismatch",
+        );
+        // incomplete and extended proofs
+        let mut proof = proof_of(1);
+        proof.pop();
+        expect_error(
+            query_receipt_proof(&deps, &receipts_root, 1, proof),
+            "Invalid Merkle-Patricia proof: missing node",
+        );
+        let mut proof = proof_of(1);
+        proof.push(proof_of(5).pop().unwrap());
+        expect_error(
+            query_receipt_proof(&deps, &receipts_root, 1, proof),
+            "Invalid Merkle-Patricia proof: unused nodes",
+        );
+        expect_error(
+            query_receipt_proof(&deps, &receipts_root[1..], 1, proof_of(1)),
+            "Invalid Merkle-Patricia proof: root must be 32 bytes long, got 31",
+        );
+    }
+
+    // RFC 6962 reference tree, see https://github.com/google/certificate-transparency/blob/master/cpp/merkletree/merkle_tree_test.cc
+    const RFC6962_LEAVES: [&str; 8] = [
+        "",
+        "00",
+        "10",
+        "2021",
+        "3031",
+        "40414243",
+        "5051525354555657",
+        "606162636465666768696a6b6c6d6e6f",
+    ];
+    // Roots of the trees of the first 1 to 8 leaves
+    const RFC6962_ROOTS: [&str; 8] = [
+        "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+        "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+        "aeb6bcfe274b70a14fb067a5e5578264db0fa9b51af5e0ba159158f329e06e77",
+        "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+        "4e3bbb1f7b478dcfe71fb631631519a3bca12c9aefca1612bfce4c13a86264d4",
+        "76e67dadbcdf1e10e1b74ddc608abd2f98dfb16fbce75277b5232a127f2087ef",
+        "ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c",
+        "5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328",
+    ];
+
+    fn rfc6962_root(leaves: &[Vec<u8>]) -> Vec<u8> {
+        if leaves.len() == 1 {
+            return merkle::leaf_hash(&leaves[0]);
+        }
+        let split = leaves.len().next_power_of_two() / 2;
+        merkle::inner_hash(
+            &rfc6962_root(&leaves[..split]),
+            &rfc6962_root(&leaves[split..]),
+        )
+    }
+
+    /// Aunts of the leaf at `index`, innermost first
+    fn rfc6962_aunts(leaves: &[Vec<u8>], index: usize) -> Vec<Binary> {
+        if leaves.len() == 1 {
+            return vec![];
+        }
+        let split = leaves.len().next_power_of_two() / 2;
+        let (mut aunts, sibling) = if index < split {
+            (
+                rfc6962_aunts(&leaves[..split], index),
+                rfc6962_root(&leaves[split..]),
+            )
+        } else {
+            (
+                rfc6962_aunts(&leaves[split..], index - split),
+                rfc6962_root(&leaves[..split]),
+            )
+        };
+        aunts.push(Binary(sibling));
+        aunts
+    }
+
+    fn query_tendermint_merkle_proof(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        leaf: &[u8],
+        index: u64,
+        total: u64,
+        aunts: Vec<Binary>,
+        root: &[u8],
+    ) -> StdResult<VerifyResponse> {
+        let query_msg = QueryMsg::VerifyTendermintMerkleProof {
+            leaf: Binary(leaf.to_vec()),
+            index,
+            total,
+            aunts,
+            root: Binary(root.to_vec()),
+        };
+        query(deps, query_msg).map(|raw| from_binary(&raw).unwrap())
+    }
+
+    #[test]
+    fn tendermint_merkle_proof_verify_works() {
+        let deps = setup();
+        let leaves: Vec<Vec<u8>> = RFC6962_LEAVES
+            .iter()
+            .map(|leaf| hex::decode(leaf).unwrap())
+            .collect();
+
+        for total in 1..=leaves.len() {
+            let tree = &leaves[..total];
+            let root = hex::decode(RFC6962_ROOTS[total - 1]).unwrap();
+            assert_eq!(rfc6962_root(tree), root);
+
+            for (index, leaf) in tree.iter().enumerate() {
+                let aunts = rfc6962_aunts(tree, index);
+                let res = query_tendermint_merkle_proof(
+                    &deps,
+                    leaf,
+                    index as u64,
+                    total as u64,
+                    aunts,
+                    &root,
+                )
+                .unwrap();
+                assert!(res.verifies, "leaf {} of {}", index, total);
+            }
+        }
+    }
+
+    // Inclusion proofs of the reference tree, see `inclusionProofs` in
+    // https://github.com/transparency-dev/merkle/blob/main/proof/verify_test.go
+    // (leaf index, tree size, aunts)
+    const RFC6962_INCLUSION_PROOFS: [(u64, u64, &[&str]); 5] = [
+        (0, 1, &[]),
+        (
+            0,
+            8,
+            &[
+                "96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7",
+                "5f083f0a1a33ca076a95279832580db3e0ef4584bdff1f54c8a360f50de3031e",
+                "6b47aaf29ee3c2af9af889bc1fb9254dabd31177f16232dd6aab035ca39bf6e4",
+            ],
+        ),
+        (
+            5,
+            8,
+            &[
+                "bc1a0643b12e4d2d7c77918f44e0f4f79a838b6cf9ec5b5c283e1f4d88599e6b",
+                "ca854ea128ed050b41b35ffc1b87b8eb2bde461e9e3b5596ece6b9d5975a0ae0",
+                "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+            ],
+        ),
+        (
+            2,
+            3,
+            &["fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125"],
+        ),
+        (
+            1,
+            5,
+            &[
+                "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+                "5f083f0a1a33ca076a95279832580db3e0ef4584bdff1f54c8a360f50de3031e",
+                "bc1a0643b12e4d2d7c77918f44e0f4f79a838b6cf9ec5b5c283e1f4d88599e6b",
+            ],
+        ),
+    ];
+
+    #[test]
+    fn tendermint_merkle_proof_reference_vectors() {
+        let deps = setup();
+        let leaves: Vec<Vec<u8>> = RFC6962_LEAVES
+            .iter()
+            .map(|leaf| hex::decode(leaf).unwrap())
+            .collect();
+
+        for (index, total, aunts) in RFC6962_INCLUSION_PROOFS.iter() {
+            let root = hex::decode(RFC6962_ROOTS[*total as usize - 1]).unwrap();
+            let aunts: Vec<Binary> = aunts
+                .iter()
+                .map(|aunt| Binary(hex::decode(aunt).unwrap()))
+                .collect();
+
+            let res = query_tendermint_merkle_proof(
+                &deps,
+                &leaves[*index as usize],
+                *index,
+                *total,
+                aunts.clone(),
+                &root,
+            )
+            .unwrap();
+            assert!(res.verifies, "leaf {} of {}", index, total);
+
+            // the proof of leaf `index` does not prove any other leaf at that index
+            for (other, leaf) in leaves.iter().enumerate() {
+                if other == *index as usize {
+                    continue;
+                }
+                let res = query_tendermint_merkle_proof(
+                    &deps,
+                    leaf,
+                    *index,
+                    *total,
+                    aunts.clone(),
+                    &root,
+                )
+                .unwrap();
+                assert!(!res.verifies, "leaf {} as {} of {}", other, index, total);
+            }
+        }
+    }
+
+    #[test]
+    fn tendermint_merkle_proof_verify_fails() {
+        let deps = setup();
+        let leaves: Vec<Vec<u8>> = RFC6962_LEAVES
+            .iter()
+            .map(|leaf| hex::decode(leaf).unwrap())
+            .collect();
+        let root = hex::decode(RFC6962_ROOTS[6]).unwrap();
+        let tree = &leaves[..7];
+
+        // other leaf
+        let aunts = rfc6962_aunts(tree, 2);
+        let res = query_tendermint_merkle_proof(&deps, &leaves[3], 2, 7, aunts.clone(), &root);
+        assert!(!res.unwrap().verifies);
+        // other index with the same number of aunts
+        let res = query_tendermint_merkle_proof(&deps, &leaves[2], 3, 7, aunts.clone(), &root);
+        assert!(!res.unwrap().verifies);
+        // other root
+        let other_root = hex::decode(RFC6962_ROOTS[7]).unwrap();
+        let res = query_tendermint_merkle_proof(&deps, &leaves[2], 2, 7, aunts, &other_root);
+        assert!(!res.unwrap().verifies);
+        // an inner node is not a leaf: the tree of 4 leaves does not contain its left subtree
+        // root as first of 2 leaves
+        let inner = rfc6962_root(&leaves[..2]);
+        let aunts = vec![Binary(rfc6962_root(&leaves[2..4]))];
+        let root = hex::decode(RFC6962_ROOTS[3]).unwrap();
+        assert_eq!(merkle::inner_hash(&inner, aunts[0].as_slice()), root);
+        let res = query_tendermint_merkle_proof(&deps, &inner, 0, 2, aunts, &root);
+        assert!(!res.unwrap().verifies);
+    }
+
+    #[test]
+    fn tendermint_merkle_proof_verify_errors() {
+        let deps = setup();
+        let leaves: Vec<Vec<u8>> = RFC6962_LEAVES
+            .iter()
+            .map(|leaf| hex::decode(leaf).unwrap())
+            .collect();
+        let root = hex::decode(RFC6962_ROOTS[4]).unwrap();
+        let tree = &leaves[..5];
+
+        let expect_error = |res: StdResult<VerifyResponse>, code: u32, message: &str| {
+            assert_eq!(
+                res.unwrap(),
+                VerifyResponse {
+                    verifies: false,
+                    failure_reason: Some(FailureReason {
+                        code,
+                        message: message.to_string(),
+                    }),
+                }
+            )
+        };
+
+        expect_error(
+            query_tendermint_merkle_proof(&deps, &leaves[0], 5, 5, vec![], &root),
+            103,
+            "Leaf index 5 out of range (total 5)",
+        );
+        expect_error(
+            query_tendermint_merkle_proof(&deps, &leaves[4], 4, 5, rfc6962_aunts(tree, 0), &root),
+            103,
+            "Invalid Merkle proof: expected 1 aunts, got 3",
+        );
+        let mut aunts = rfc6962_aunts(tree, 0);
+        aunts[1] = Binary(vec![0u8; 31]);
+        expect_error(
+            query_tendermint_merkle_proof(&deps, &leaves[0], 0, 5, aunts, &root),
+            103,
+            "Invalid Merkle proof: hashes must be 32 bytes long, got 31",
+        );
+    }
 
-        let res = query(&deps, verify_msg);
-        assert!(res.is_err());
+    fn keccak_pair(a: &[u8], b: &[u8]) -> Vec<u8> {
+        let (a, b) = if a <= b { (a, b) } else { (b, a) };
+        Keccak256::new().chain(a).chain(b).finalize().to_vec()
+    }
+
+    /// Builds an OpenZeppelin style tree (sorted pairs, unpaired nodes are promoted).
+    /// Returns the root and the proof of the leaf at `index`.
+    fn keccak_tree(leaves: &[Vec<u8>], mut index: usize) -> (Vec<u8>, Vec<Binary>) {
+        let mut level = leaves.to_vec();
+        let mut proof = vec![];
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(Binary(sibling.clone()));
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => keccak_pair(a, b),
+                    [a] => a.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            index /= 2;
+        }
+        (level[0].clone(), proof)
+    }
+
+    fn query_keccak_merkle_proof(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        leaf: &[u8],
+        proof: Vec<Binary>,
+        root: &[u8],
+    ) -> StdResult<VerifyResponse> {
+        let query_msg = QueryMsg::VerifyKeccakMerkleProof {
+            leaf: Binary(leaf.to_vec()),
+            proof,
+            root: Binary(root.to_vec()),
+        };
+        query(deps, query_msg).map(|raw| from_binary(&raw).unwrap())
+    }
+
+    #[test]
+    fn keccak_merkle_proof_verify_works() {
+        let deps = setup();
+        // airdrop style leaves: keccak256(index || amount)
+        let leaves: Vec<Vec<u8>> = (0u64..7)
+            .map(|i| {
+                let mut leaf = i.to_be_bytes().to_vec();
+                leaf.extend_from_slice(&(1000 * i).to_be_bytes());
+                Keccak256::digest(&leaf).to_vec()
+            })
+            .collect();
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let (root, proof) = keccak_tree(&leaves, index);
+            let res = query_keccak_merkle_proof(&deps, leaf, proof.clone(), &root).unwrap();
+            assert!(res.verifies, "leaf {}", index);
+
+            // wrong leaf
+            let other = &leaves[(index + 1) % leaves.len()];
+            let res = query_keccak_merkle_proof(&deps, other, proof, &root).unwrap();
+            assert!(!res.verifies, "leaf {}", index);
+        }
+
+        // single leaf tree
+        let res = query_keccak_merkle_proof(&deps, &leaves[0], vec![], &leaves[0]).unwrap();
+        assert!(res.verifies);
+    }
+
+    // The tree of OpenZeppelin's `MerkleProof` test suite: `keccak256` leaves of the letters
+    // a to z, built with merkletreejs `{ hashLeaves: true, sortPairs: true }`.
+    // See https://github.com/OpenZeppelin/openzeppelin-contracts/blob/v4.3.0/test/utils/cryptography/MerkleProof.test.js
+    const OZ_MERKLE_ROOT: &str = "47df3f5ed10f50791c589f1f45926c45023be386add6f1af8aedc037ff53d15a";
+    // (leaf, proof)
+    const OZ_MERKLE_PROOFS: [(&str, &[&str]); 2] = [
+        (
+            "a",
+            &[
+                "b5553de315e0edf504d9150af82dafa5c4667fa618ed0a6f19c69b41166c5510",
+                "d253a52d4cb00de2895e85f2529e2976e6aaaa5c18106b68ab66813e14415669",
+                "65d48a9a71389c608324abbe0156c2f0dd4f751d76d5f789779f4b1a25ef4a02",
+                "83d4f0b3ec29205d420087ea6fd7d4921dd43df3493df649bce90811a6aa8ef8",
+                "003acd812bb3a1d9984c913a2ca579edbfc09b70f3f5f275102d6861d7ef4395",
+            ],
+        ),
+        // the last leaf is unpaired on the first two levels
+        (
+            "z",
+            &[
+                "83847cf31c36389df832d0d4d3df7cf28f211e3f83173e5c157bab31573d61f3",
+                "e0745da87485cb60157fc5f044dea56e697f5d7febb17ef90182ab36d283e715",
+                "e7ae50e782cbf16cddca4fb340415ed5203a28781d47c20b81e717c4332b97d9",
+            ],
+        ),
+    ];
+
+    #[test]
+    fn keccak_merkle_proof_reference_vectors() {
+        let deps = setup();
+        let root = hex::decode(OZ_MERKLE_ROOT).unwrap();
+
+        for (element, proof) in OZ_MERKLE_PROOFS.iter() {
+            let leaf = Keccak256::digest(element.as_bytes());
+            let proof: Vec<Binary> = proof
+                .iter()
+                .map(|sibling| Binary(hex::decode(sibling).unwrap()))
+                .collect();
+
+            let res = query_keccak_merkle_proof(&deps, &leaf, proof.clone(), &root).unwrap();
+            assert!(res.verifies, "leaf {}", element);
+
+            // the proof of one leaf does not prove any other leaf
+            for other in b'a'..=b'z' {
+                if other == element.as_bytes()[0] {
+                    continue;
+                }
+                let leaf = Keccak256::digest(&[other]);
+                let res = query_keccak_merkle_proof(&deps, &leaf, proof.clone(), &root).unwrap();
+                assert!(
+                    !res.verifies,
+                    "leaf {} with proof of {}",
+                    other as char, element
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn keccak_merkle_proof_verify_errors() {
+        let deps = setup();
+        let leaves: Vec<Vec<u8>> = (0u8..4).map(|i| Keccak256::digest(&[i]).to_vec()).collect();
+        let (root, proof) = keccak_tree(&leaves, 1);
+
+        assert_eq!(
+            query_keccak_merkle_proof(&deps, &[1u8], proof, &root).unwrap(),
+            VerifyResponse {
+                verifies: false,
+                failure_reason: Some(FailureReason {
+                    code: 103,
+                    message: "Invalid Merkle proof: hashes must be 32 bytes long, got 1"
+                        .to_string(),
+                }),
+            }
+        );
     }
 
     #[test]
@@ -217,8 +4409,412 @@ mod tests {
         assert_eq!(
             res,
             ListVerificationsResponse {
-                verification_schemes: vec!["secp256k1".into(),]
+                verification_schemes: vec![
+                    "secp256k1".into(),
+                    "secp256k1_batch".into(),
+                    "adr036".into(),
+                    "secp256r1".into(),
+                    "webauthn".into(),
+                    "ethereum_text".into(),
+                    "ethereum_transaction".into(),
+                    "eip712".into(),
+                    "bitcoin_message".into(),
+                    "bip340".into(),
+                    "bls12_381".into(),
+                    "ed25519".into(),
+                    "ed25519_batch".into(),
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn capabilities_works() {
+        let deps = setup();
+
+        let raw = query(&deps, QueryMsg::Capabilities {}).unwrap();
+        let res: CapabilitiesResponse = from_binary(&raw).unwrap();
+
+        assert_eq!(res.version.contract, CONTRACT_NAME);
+        assert_eq!(res.version.version, VERSION);
+        let schemes: Vec<_> = res.schemes.iter().map(|s| s.scheme.clone()).collect();
+        assert_eq!(schemes, list_verifications(&deps));
+
+        let secp256k1 = &res.schemes[0];
+        assert_eq!(
+            secp256k1,
+            &SchemeCapabilities {
+                scheme: "secp256k1".into(),
+                key_encodings: vec![KeyEncoding::Sec1Compressed, KeyEncoding::Sec1Uncompressed],
+                hash_modes: vec![HashMode::Sha256, HashMode::Keccak256, HashMode::Prehashed],
+                batch: false,
+                max_signature_len: 64,
+                max_public_key_len: 65,
+                max_batch_size: None,
+            }
+        );
+        let batch: Vec<_> = res
+            .schemes
+            .iter()
+            .filter(|s| s.batch)
+            .map(|s| s.scheme.as_str())
+            .collect();
+        assert_eq!(batch, vec!["secp256k1_batch", "bls12_381", "ed25519_batch"]);
+    }
+
+    #[test]
+    fn capabilities_requires_version() {
+        // contracts instantiated before the version was stored
+        let deps = mock_dependencies(20, &[]);
+        match query(&deps, QueryMsg::Capabilities {}) {
+            Err(StdError::NotFound { .. }) => {}
+            res => panic!("expected not found error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn capabilities_limits_match_verify_queries() {
+        /// `verifies` of any verification response
+        #[derive(serde::Deserialize)]
+        struct Verifies {
+            verifies: bool,
+        }
+
+        let deps = setup();
+        let verifies = |verify_msg: QueryMsg| {
+            query(&deps, verify_msg)
+                .map(|raw| from_binary::<Verifies>(&raw).unwrap().verifies)
+                .unwrap_or(false)
+        };
+
+        let adr036_key = SigningKey::random(&mut OsRng);
+        let adr036_data = b"execute batch 42".to_vec();
+        let (adr036_signer, adr036_signature, adr036_public_key) =
+            adr036_sign(&adr036_key, "terra", &adr036_data);
+        let webauthn_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let webauthn_public_key = p256::EncodedPoint::from(&webauthn_key.verify_key());
+        let authenticator_data = webauthn_authenticator_data(WEBAUTHN_RP_ID, 0x05);
+        let client_data_json =
+            webauthn_client_data("webauthn.get", &WEBAUTHN_CHALLENGE, WEBAUTHN_ORIGIN);
+        let (_, bip340_public_key, bip340_message, bip340_signature, _) = BIP340_VECTORS[0];
+
+        for capabilities in verification_schemes() {
+            // a valid signature and public key of the scheme, and its query for a signature
+            let (signature, public_key, verify_msg): (_, _, Box<dyn Fn(Vec<u8>) -> QueryMsg>) =
+                match capabilities.scheme.as_str() {
+                    "secp256k1" => (
+                        hex::decode(SECP256K1_SIGNATURE_HEX).unwrap(),
+                        hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap(),
+                        Box::new(|signature| QueryMsg::VerifySecp256k1Signature {
+                            message: Binary(hex::decode(SECP256K1_MESSAGE_HEX).unwrap()),
+                            signature: Binary(signature),
+                            public_key: Binary(hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap()),
+                            hash_function: HashFunction::Sha256,
+                        }),
+                    ),
+                    "secp256k1_batch" => (
+                        hex::decode(SECP256K1_SIGNATURE_HEX).unwrap(),
+                        hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap(),
+                        Box::new(|signature| QueryMsg::VerifySecp256k1Batch {
+                            messages: vec![Binary(hex::decode(SECP256K1_MESSAGE_HEX).unwrap())],
+                            signatures: vec![Binary(signature)],
+                            public_keys: vec![Binary(
+                                hex::decode(SECP256K1_PUBLIC_KEY_HEX).unwrap(),
+                            )],
+                            hash_function: HashFunction::Sha256,
+                        }),
+                    ),
+                    "adr036" => (
+                        adr036_signature.0.clone(),
+                        adr036_public_key.0.clone(),
+                        Box::new(|signature| QueryMsg::VerifyAdr036Signature {
+                            signer: adr036_signer.clone(),
+                            data: Binary(adr036_data.clone()),
+                            signature: Binary(signature),
+                            public_key: adr036_public_key.clone(),
+                        }),
+                    ),
+                    "secp256r1" => (
+                        hex::decode(SECP256R1_SIGNATURE_HEX).unwrap(),
+                        hex::decode(SECP256R1_PUBLIC_KEY_HEX).unwrap(),
+                        Box::new(|signature| QueryMsg::VerifySecp256r1Signature {
+                            message: Binary(SECP256R1_MESSAGE.to_vec()),
+                            signature: Binary(signature),
+                            public_key: Binary(hex::decode(SECP256R1_PUBLIC_KEY_HEX).unwrap()),
+                        }),
+                    ),
+                    "webauthn" => (
+                        webauthn_sign(&webauthn_key, &authenticator_data, &client_data_json),
+                        webauthn_public_key.as_bytes().to_vec(),
+                        Box::new(|signature| {
+                            webauthn_query(
+                                &authenticator_data,
+                                &client_data_json,
+                                &signature,
+                                webauthn_public_key.as_bytes(),
+                                false,
+                            )
+                        }),
+                    ),
+                    "ethereum_text" => (
+                        hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap(),
+                        hex::decode(&ETHEREUM_SIGNER_ADDRESS[2..]).unwrap(),
+                        Box::new(|signature| QueryMsg::VerifyEthereumText {
+                            message: ETHEREUM_MESSAGE.into(),
+                            signature: Binary(signature),
+                            signer_address: ETHEREUM_SIGNER_ADDRESS.into(),
+                        }),
+                    ),
+                    "ethereum_transaction" => (
+                        [
+                            hex!(
+                                "b9299dab50b3cddcaecd64b29bfbd5cd30fac1a1adea1b359a13c4e5171492a6"
+                            ),
+                            hex!(
+                                "573059c66d894684488f92e7ce1f91b158ca57b0235485625b576a3b98c480ac"
+                            ),
+                        ]
+                        .concat(),
+                        hex!("0a65766695a712af41b5cfecaad217b1a11cb22a").to_vec(),
+                        Box::new(|signature: Vec<u8>| {
+                            let mut msg = rinkeby_transaction(
+                                "0x0a65766695a712af41b5cfecaad217b1a11cb22a",
+                                0x2b,
+                            );
+                            if let QueryMsg::VerifyEthereumTransaction { r, s, .. } = &mut msg {
+                                *r = Binary(signature[..32].to_vec());
+                                *s = Binary(signature[32..].to_vec());
+                            }
+                            msg
+                        }),
+                    ),
+                    "eip712" => (
+                        hex::decode(EIP712_MAIL_SIGNATURE_HEX).unwrap(),
+                        hex::decode(&EIP712_MAIL_SIGNER[2..]).unwrap(),
+                        Box::new(|signature| QueryMsg::VerifyEip712Signature {
+                            domain: eip712_mail_domain(),
+                            types: eip712_mail_types(),
+                            primary_type: "Mail".to_string(),
+                            message: EIP712_MAIL_MESSAGE.to_string(),
+                            signature: Binary(signature),
+                            signer_address: EIP712_MAIL_SIGNER.to_string(),
+                        }),
+                    ),
+                    "bitcoin_message" => (
+                        Binary::from_base64(BITCOIN_SIGNATURE).unwrap().0,
+                        match bitcoin::decode_address(BITCOIN_ADDRESS).unwrap() {
+                            bitcoin::Address::P2pkh(hash) | bitcoin::Address::P2wpkh(hash) => {
+                                hash.to_vec()
+                            }
+                        },
+                        Box::new(|signature| QueryMsg::VerifyBitcoinMessage {
+                            message: BITCOIN_MESSAGE.to_string(),
+                            signature: Binary(signature),
+                            address: BITCOIN_ADDRESS.to_string(),
+                        }),
+                    ),
+                    "bip340" => (
+                        hex::decode(bip340_signature).unwrap(),
+                        hex::decode(bip340_public_key).unwrap(),
+                        Box::new(|signature| QueryMsg::VerifySchnorrSignature {
+                            message: Binary(hex::decode(bip340_message).unwrap()),
+                            signature: Binary(signature),
+                            public_key: Binary(hex::decode(bip340_public_key).unwrap()),
+                        }),
+                    ),
+                    "bls12_381" => (
+                        hex::decode(BLS_SIGNATURE_HEX).unwrap(),
+                        hex::decode(BLS_PUBLIC_KEY_HEX).unwrap(),
+                        Box::new(|signature| QueryMsg::VerifyBlsAggregate {
+                            messages: vec![Binary(hex::decode(BLS_MESSAGE_HEX).unwrap())],
+                            signature: Binary(signature),
+                            public_keys: vec![Binary(hex::decode(BLS_PUBLIC_KEY_HEX).unwrap())],
+                            dst: None,
+                        }),
+                    ),
+                    "ed25519" => (
+                        hex::decode(ED25519_SIGNATURE_HEX).unwrap(),
+                        hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap(),
+                        Box::new(|signature| QueryMsg::VerifyTendermintSignature {
+                            message: Binary(hex::decode(ED25519_MESSAGE_HEX).unwrap()),
+                            signature: Binary(signature),
+                            public_key: Binary(hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap()),
+                        }),
+                    ),
+                    "ed25519_batch" => (
+                        hex::decode(ED25519_SIGNATURE_HEX).unwrap(),
+                        hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap(),
+                        Box::new(|signature| QueryMsg::VerifyTendermintBatch {
+                            messages: vec![Binary(hex::decode(ED25519_MESSAGE_HEX).unwrap())],
+                            signatures: vec![Binary(signature)],
+                            public_keys: vec![Binary(hex::decode(ED25519_PUBLIC_KEY_HEX).unwrap())],
+                        }),
+                    ),
+                    scheme => panic!("no test inputs for scheme {}", scheme),
+                };
+            let scheme = capabilities.scheme.as_str();
+            let max_signature_len = capabilities.max_signature_len as usize;
+
+            // DER signatures are variable length, all others are fixed length
+            if scheme == "webauthn" {
+                assert!(signature.len() <= max_signature_len, "{}", scheme);
+            } else {
+                assert_eq!(signature.len(), max_signature_len, "{}", scheme);
             }
+            assert!(
+                public_key.len() <= capabilities.max_public_key_len as usize,
+                "{}",
+                scheme
+            );
+            assert!(verifies(verify_msg(signature.clone())), "{}", scheme);
+
+            // longer signatures are never accepted
+            let mut too_long = signature;
+            too_long.resize(max_signature_len + 1, 0);
+            assert!(!verifies(verify_msg(too_long)), "{}", scheme);
+        }
+    }
+
+    #[test]
+    fn hash_works() {
+        let deps = setup();
+
+        let msg = QueryMsg::Hash {
+            algorithm: HashAlgorithm::Keccak256,
+            data: Binary::from(b"abc".as_ref()),
+        };
+        let res: HashResponse = from_binary(&query(&deps, msg).unwrap()).unwrap();
+        assert_eq!(
+            res.hash,
+            Binary(
+                hex!("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45").to_vec()
+            )
+        );
+
+        let msg = QueryMsg::HashBatch {
+            algorithm: HashAlgorithm::Sha256,
+            data: vec![Binary(vec![]), Binary::from(b"abc".as_ref())],
+        };
+        let res: HashBatchResponse = from_binary(&query(&deps, msg).unwrap()).unwrap();
+        assert_eq!(
+            res.hashes,
+            vec![
+                Binary(
+                    hex!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+                        .to_vec()
+                ),
+                Binary(
+                    hex!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+                        .to_vec()
+                ),
+            ]
+        );
+
+        let msg = QueryMsg::HashBatch {
+            algorithm: HashAlgorithm::Ripemd160,
+            data: vec![],
+        };
+        let res: HashBatchResponse = from_binary(&query(&deps, msg).unwrap()).unwrap();
+        assert!(res.hashes.is_empty());
+    }
+
+    const CONSUMER: &str = "meta_tx_contract";
+
+    fn consume_signature(
+        deps: &mut Extern<MockStorage, MockApi, MockQuerier>,
+        caller: &str,
+        signing_key: &SigningKey,
+        message: &[u8],
+    ) -> StdResult<HandleResponse> {
+        let signature: Signature = signing_key.sign(message);
+        let msg = HandleMsg::ConsumeSignature {
+            digest: Binary(Sha256::digest(message).to_vec()),
+            signature: Binary::from(signature.as_ref()),
+            pubkey: Binary::from(VerifyingKey::from(signing_key).to_bytes().as_slice()),
+        };
+        handle(deps, mock_env(HumanAddr::from(caller), &[]), msg)
+    }
+
+    fn is_consumed(
+        deps: &Extern<MockStorage, MockApi, MockQuerier>,
+        caller: &str,
+        message: &[u8],
+    ) -> bool {
+        let msg = QueryMsg::IsDigestConsumed {
+            caller: HumanAddr::from(caller),
+            digest: Binary(Sha256::digest(message).to_vec()),
+        };
+        let res: DigestConsumedResponse = from_binary(&query(deps, msg).unwrap()).unwrap();
+        res.consumed
+    }
+
+    #[test]
+    fn consume_signature_works() {
+        let mut deps = setup();
+        let signing_key = SigningKey::random(&mut OsRng);
+        let message = b"transfer 100 to bob, nonce 1";
+
+        assert!(!is_consumed(&deps, CONSUMER, message));
+        let res = consume_signature(&mut deps, CONSUMER, &signing_key, message).unwrap();
+        assert_eq!(
+            res.log,
+            vec![
+                log("action", "consume_signature"),
+                log("caller", CONSUMER),
+                log("digest", hex::encode(Sha256::digest(message))),
+            ]
         );
+        assert!(is_consumed(&deps, CONSUMER, message));
+
+        // replay
+        match consume_signature(&mut deps, CONSUMER, &signing_key, message) {
+            Err(StdError::GenericErr { msg, .. }) => assert_eq!(msg, "Signature already consumed"),
+            res => panic!("expected replay error, got {:?}", res),
+        }
+
+        // namespaces are per caller
+        assert!(!is_consumed(&deps, "other_contract", message));
+        consume_signature(&mut deps, "other_contract", &signing_key, message).unwrap();
+        assert!(is_consumed(&deps, "other_contract", message));
+
+        // other messages are not affected
+        consume_signature(&mut deps, CONSUMER, &signing_key, b"nonce 2").unwrap();
+    }
+
+    #[test]
+    fn consume_signature_errors() {
+        let mut deps = setup();
+        let signing_key = SigningKey::random(&mut OsRng);
+        let message = b"transfer 100 to bob, nonce 1";
+
+        // signature by another key
+        let signature: Signature = SigningKey::random(&mut OsRng).sign(message);
+        let msg = HandleMsg::ConsumeSignature {
+            digest: Binary(Sha256::digest(message).to_vec()),
+            signature: Binary::from(signature.as_ref()),
+            pubkey: Binary::from(VerifyingKey::from(&signing_key).to_bytes().as_slice()),
+        };
+        match handle(&mut deps, mock_env(HumanAddr::from(CONSUMER), &[]), msg) {
+            Err(StdError::Unauthorized { .. }) => {}
+            res => panic!("expected unauthorized error, got {:?}", res),
+        }
+
+        // the digest must be 32 bytes long
+        let signature: Signature = signing_key.sign(message);
+        let msg = HandleMsg::ConsumeSignature {
+            digest: Binary(message.to_vec()),
+            signature: Binary::from(signature.as_ref()),
+            pubkey: Binary::from(VerifyingKey::from(&signing_key).to_bytes().as_slice()),
+        };
+        match handle(&mut deps, mock_env(HumanAddr::from(CONSUMER), &[]), msg) {
+            Err(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "Verification error: Invalid hash format")
+            }
+            res => panic!("expected invalid hash format error, got {:?}", res),
+        }
+
+        // failed attempts do not consume the digest
+        assert!(!is_consumed(&deps, CONSUMER, message));
+        consume_signature(&mut deps, CONSUMER, &signing_key, message).unwrap();
     }
 }
@@ -0,0 +1,42 @@
+use bech32::ToBase32;
+use ripemd160::Ripemd160;
+use sha2::{Digest, Sha256};
+
+use crate::errors::ContractError;
+use crate::secp256k1::compress_pubkey;
+
+/// Derives the bech32 account address of a secp256k1 public key:
+/// `bech32(prefix, ripemd160(sha256(compressed_public_key)))`.
+pub fn address_from_pubkey(prefix: &str, public_key: &[u8]) -> Result<String, ContractError> {
+    let compressed = compress_pubkey(public_key)?;
+    let hash = Ripemd160::digest(&Sha256::digest(&compressed));
+    bech32::encode(prefix, hash.to_base32()).map_err(|err| ContractError::InvalidAddress {
+        msg: format!("Invalid bech32 prefix: {}", err),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_from_pubkey_works() {
+        // Test data from https://github.com/cosmos/cosmjs/blob/v0.24.0/packages/amino/src/addresses.spec.ts
+        let public_key = base64_decode("AtQaCqFnshaZQp6rIkvAPyzThvCvXSDO+9AzbxVErqJP");
+        assert_eq!(
+            address_from_pubkey("cosmos", &public_key).unwrap(),
+            "cosmos1h806c7khnvmjlywdrkdgk2vrayy2mmvf9rxk2r"
+        );
+    }
+
+    #[test]
+    fn address_from_pubkey_errors() {
+        let public_key = base64_decode("AtQaCqFnshaZQp6rIkvAPyzThvCvXSDO+9AzbxVErqJP");
+        assert!(address_from_pubkey("", &public_key).is_err());
+        assert!(address_from_pubkey("cosmos", &public_key[1..]).is_err());
+    }
+
+    fn base64_decode(encoded: &str) -> Vec<u8> {
+        cosmwasm_std::Binary::from_base64(encoded).unwrap().0
+    }
+}
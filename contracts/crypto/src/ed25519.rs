@@ -0,0 +1,129 @@
+use cosmwasm_crypto::EDDSA_PUBKEY_LEN;
+use ed25519_zebra::{batch, Signature, VerificationKeyBytes};
+use rand_core::{impls, CryptoRng, Error, RngCore};
+use sha2::{Digest, Sha256};
+use std::convert::TryFrom;
+
+use crate::batch::BatchEntry;
+use crate::errors::ContractError;
+
+/// Domain separator for the seed of the batch verification coefficients
+const BATCH_SEED_DOMAIN: &[u8] = b"axelar-crypto/ed25519-batch/v1";
+
+/// Verifies a batch of ed25519 signatures and returns the indices of the entries that do not
+/// verify, in order. Entries with a malformed signature or public key do not verify either; they
+/// come with the reason.
+///
+/// The well formed entries are checked with a single batch verification first; only if that fails
+/// are they verified one by one to find the culprits.
+pub fn batch_verify(entries: &[BatchEntry]) -> Vec<(usize, Option<ContractError>)> {
+    let mut failed = vec![];
+    let mut items = Vec::with_capacity(entries.len());
+    for (i, (message, signature, public_key)) in entries.iter().enumerate() {
+        let signature = <[u8; 64]>::try_from(*signature);
+        let public_key = <[u8; EDDSA_PUBKEY_LEN]>::try_from(*public_key);
+        match (signature, public_key) {
+            (Ok(signature), Ok(public_key)) => {
+                let item: batch::Item = (
+                    VerificationKeyBytes::from(public_key),
+                    Signature::from(signature),
+                    message,
+                )
+                    .into();
+                items.push((i, item));
+            }
+            (Err(_), _) => failed.push((i, Some(ContractError::InvalidSignatureFormat))),
+            (_, Err(_)) => failed.push((i, Some(ContractError::InvalidPubkeyFormat))),
+        }
+    }
+
+    let mut verifier = batch::Verifier::new();
+    for (_, item) in items.iter().cloned() {
+        verifier.queue(item);
+    }
+    if verifier.verify(BatchRng::new(entries)).is_err() {
+        failed.extend(
+            items
+                .into_iter()
+                .filter(|(_, item)| item.clone().verify_single().is_err())
+                .map(|(i, _)| (i, None)),
+        );
+        failed.sort_unstable_by_key(|(i, _)| *i);
+    }
+    failed
+}
+
+/// Deterministic source of the random batch coefficients.
+///
+/// Contracts have no access to an entropy source, so the coefficients are derived from a hash of
+/// the whole batch instead: they are unknown before all signatures are fixed, and any change to the
+/// batch changes all of them.
+struct BatchRng {
+    seed: [u8; 32],
+    counter: u64,
+}
+
+impl BatchRng {
+    fn new(entries: &[BatchEntry]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(BATCH_SEED_DOMAIN);
+        for (message, signature, public_key) in entries {
+            hasher.update(public_key);
+            hasher.update(signature);
+            hasher.update((message.len() as u64).to_be_bytes());
+            hasher.update(message);
+        }
+        BatchRng {
+            seed: hasher.finalize().into(),
+            counter: 0,
+        }
+    }
+}
+
+impl RngCore for BatchRng {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(32) {
+            let block = Sha256::new()
+                .chain(self.seed)
+                .chain(self.counter.to_be_bytes())
+                .finalize();
+            self.counter += 1;
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for BatchRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_rng_is_deterministic() {
+        let entries: Vec<BatchEntry> = vec![(b"m", b"s", b"k")];
+        let mut a = BatchRng::new(&entries);
+        let mut b = BatchRng::new(&entries);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_ne!(a.next_u64(), BatchRng::new(&entries).next_u64());
+
+        let other: Vec<BatchEntry> = vec![(b"n", b"s", b"k")];
+        assert_ne!(
+            BatchRng::new(&entries).next_u64(),
+            BatchRng::new(&other).next_u64()
+        );
+    }
+}
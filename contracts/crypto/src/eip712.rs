@@ -0,0 +1,755 @@
+use std::collections::BTreeSet;
+
+use axelar_gateway_contracts::crypto::{Eip712Domain, Eip712Field, Eip712Type};
+use sha3::{Digest, Keccak256};
+
+use crate::errors::ContractError;
+use crate::ethereum::{decode_address, ADDRESS_LEN};
+
+/// Length of an encoded value
+const WORD_LEN: usize = 32;
+
+/// Name of the domain struct type
+pub const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// Computes the EIP-712 signing hash of a typed `message`:
+/// `keccak256(0x19 || 0x01 || domainSeparator || hashStruct(message))`.
+///
+/// `message` is the JSON encoded value of `primary_type`. Integers may be given as JSON numbers
+/// or as decimal or `0x` prefixed hex strings, byte values as `0x` prefixed hex strings.
+///
+/// See https://github.com/ethereum/EIPs/blob/master/EIPS/eip-712.md
+pub fn signing_hash(
+    domain: &Eip712Domain,
+    types: &[Eip712Type],
+    primary_type: &str,
+    message: &str,
+) -> Result<[u8; 32], ContractError> {
+    let message = Parser::parse(message)?;
+    let mut hasher = Keccak256::new();
+    hasher.update([0x19, 0x01]);
+    hasher.update(domain_separator(domain)?);
+    hasher.update(hash_struct(types, primary_type, &message)?);
+    Ok(hasher.finalize().into())
+}
+
+/// Hashes the domain. Its type consists of the fields that are set, in the order of the
+/// specification, as `eth_signTypedData_v4` wallets derive it.
+pub fn domain_separator(domain: &Eip712Domain) -> Result<[u8; 32], ContractError> {
+    let mut fields = vec![];
+    let mut values = vec![];
+    let mut add = |name: &str, ty: &str, value: Value| {
+        fields.push(Eip712Field {
+            name: name.to_string(),
+            field_type: ty.to_string(),
+        });
+        values.push((name.to_string(), value));
+    };
+    if let Some(name) = &domain.name {
+        add("name", "string", Value::String(name.clone()));
+    }
+    if let Some(version) = &domain.version {
+        add("version", "string", Value::String(version.clone()));
+    }
+    if let Some(chain_id) = &domain.chain_id {
+        add("chainId", "uint256", Value::Number(chain_id.to_string()));
+    }
+    if let Some(verifying_contract) = &domain.verifying_contract {
+        add(
+            "verifyingContract",
+            "address",
+            Value::String(verifying_contract.clone()),
+        );
+    }
+    if let Some(salt) = &domain.salt {
+        add(
+            "salt",
+            "bytes32",
+            Value::String(format!("0x{}", hex::encode(salt.as_slice()))),
+        );
+    }
+    let types = [Eip712Type {
+        name: DOMAIN_TYPE.to_string(),
+        fields,
+    }];
+    hash_struct(&types, DOMAIN_TYPE, &Value::Object(values))
+}
+
+/// `keccak256(encodeType(type_name))`
+pub fn type_hash(types: &[Eip712Type], type_name: &str) -> Result<[u8; 32], ContractError> {
+    Ok(Keccak256::digest(encode_type(types, type_name)?.as_bytes()).into())
+}
+
+/// Encodes a struct type followed by the struct types it references, sorted by name, e.g.
+/// `Mail(Person from,Person to,string contents)Person(string name,address wallet)`.
+pub fn encode_type(types: &[Eip712Type], type_name: &str) -> Result<String, ContractError> {
+    let mut referenced = BTreeSet::new();
+    collect_references(types, type_name, &mut referenced)?;
+    referenced.remove(type_name);
+
+    let encode = |name: &str| -> Result<String, ContractError> {
+        let fields: Vec<String> = find_type(types, name)?
+            .fields
+            .iter()
+            .map(|field| format!("{} {}", field.field_type, field.name))
+            .collect();
+        Ok(format!("{}({})", name, fields.join(",")))
+    };
+    let mut encoded = encode(type_name)?;
+    for name in referenced {
+        encoded.push_str(&encode(&name)?);
+    }
+    Ok(encoded)
+}
+
+fn collect_references(
+    types: &[Eip712Type],
+    type_name: &str,
+    referenced: &mut BTreeSet<String>,
+) -> Result<(), ContractError> {
+    if !referenced.insert(type_name.to_string()) {
+        return Ok(());
+    }
+    for field in &find_type(types, type_name)?.fields {
+        let base = base_type(&field.field_type);
+        if is_struct(types, base) {
+            collect_references(types, base, referenced)?;
+        }
+    }
+    Ok(())
+}
+
+fn hash_struct(
+    types: &[Eip712Type],
+    type_name: &str,
+    value: &Value,
+) -> Result<[u8; 32], ContractError> {
+    let members = match value {
+        Value::Object(members) => members,
+        _ => return Err(invalid(format!("{} must be an object", type_name))),
+    };
+    let mut hasher = Keccak256::new();
+    hasher.update(type_hash(types, type_name)?);
+    for field in &find_type(types, type_name)?.fields {
+        let value = members
+            .iter()
+            .find(|(name, _)| *name == field.name)
+            .map(|(_, value)| value)
+            .ok_or_else(|| invalid(format!("missing field {}.{}", type_name, field.name)))?;
+        hasher.update(encode_value(types, &field.field_type, value)?);
+    }
+    Ok(hasher.finalize().into())
+}
+
+/// Encodes a value of type `ty` as one 32-byte word
+fn encode_value(
+    types: &[Eip712Type],
+    ty: &str,
+    value: &Value,
+) -> Result<[u8; WORD_LEN], ContractError> {
+    if let Some(element_type) = ty.strip_suffix(']') {
+        let (element_type, length) = element_type
+            .rsplit_once('[')
+            .ok_or_else(|| invalid(format!("unknown type {}", ty)))?;
+        let elements = match value {
+            Value::Array(elements) => elements,
+            _ => return Err(invalid(format!("expected an array of type {}", ty))),
+        };
+        if !length.is_empty() && length.parse::<usize>().ok() != Some(elements.len()) {
+            return Err(invalid(format!(
+                "expected {} elements of type {}, got {}",
+                length,
+                element_type,
+                elements.len()
+            )));
+        }
+        let mut hasher = Keccak256::new();
+        for element in elements {
+            hasher.update(encode_value(types, element_type, element)?);
+        }
+        return Ok(hasher.finalize().into());
+    }
+    if is_struct(types, ty) {
+        return hash_struct(types, ty, value);
+    }
+
+    let mut word = [0u8; WORD_LEN];
+    match ty {
+        "string" => word = Keccak256::digest(as_str(value, ty)?.as_bytes()).into(),
+        "bytes" => word = Keccak256::digest(&decode_hex(as_str(value, ty)?)?).into(),
+        "bool" => match value {
+            Value::Bool(b) => word[WORD_LEN - 1] = *b as u8,
+            _ => return Err(invalid(format!("expected a value of type {}", ty))),
+        },
+        "address" => {
+            word[WORD_LEN - ADDRESS_LEN..].copy_from_slice(&decode_address(as_str(value, ty)?)?)
+        }
+        _ => {
+            if let Some(size) = ty.strip_prefix("bytes") {
+                let bytes = decode_hex(as_str(value, ty)?)?;
+                if size.parse::<usize>().ok() != Some(bytes.len()) || bytes.len() > WORD_LEN {
+                    return Err(invalid(format!("expected a value of type {}", ty)));
+                }
+                word[..bytes.len()].copy_from_slice(&bytes);
+            } else if let Some(bits) = ty.strip_prefix("uint") {
+                word = encode_integer(value, ty, bits_of(bits, ty)?, false)?;
+            } else if let Some(bits) = ty.strip_prefix("int") {
+                word = encode_integer(value, ty, bits_of(bits, ty)?, true)?;
+            } else {
+                return Err(invalid(format!("unknown type {}", ty)));
+            }
+        }
+    }
+    Ok(word)
+}
+
+/// Encodes an integer as 32-byte big-endian two's complement word, checking that it fits into
+/// `bits` bits.
+fn encode_integer(
+    value: &Value,
+    ty: &str,
+    bits: usize,
+    signed: bool,
+) -> Result<[u8; WORD_LEN], ContractError> {
+    let out_of_range = || invalid(format!("value out of range for type {}", ty));
+    let text = match value {
+        Value::Number(text) | Value::String(text) => text.as_str(),
+        _ => return Err(invalid(format!("expected a value of type {}", ty))),
+    };
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) if signed => (true, digits),
+        _ => (false, text),
+    };
+    let magnitude = match digits.strip_prefix("0x") {
+        Some(hex_digits) => parse_magnitude(hex_digits, 16),
+        None => parse_magnitude(digits, 10),
+    }
+    .ok_or_else(|| invalid(format!("invalid integer {} for type {}", text, ty)))?
+    .ok_or_else(out_of_range)?;
+
+    // magnitude must be below 2^bits, 2^(bits-1) for positive signed values and at most
+    // 2^(bits-1) for negative values
+    let value_bits = if signed { bits - 1 } else { bits };
+    let limit = power_of_two(value_bits);
+    let in_range = if negative {
+        magnitude <= limit
+    } else {
+        value_bits == 256 || magnitude < limit
+    };
+    if !in_range {
+        return Err(out_of_range());
+    }
+    if !negative {
+        return Ok(magnitude);
+    }
+    // two's complement
+    let mut word = magnitude.map(|b| !b);
+    for byte in word.iter_mut().rev() {
+        let (sum, carry) = byte.overflowing_add(1);
+        *byte = sum;
+        if !carry {
+            break;
+        }
+    }
+    Ok(word)
+}
+
+/// Parses unsigned digits in `radix` into a big-endian word. Returns `None` for invalid digits
+/// and `Some(None)` if the value does not fit into 256 bits.
+fn parse_magnitude(digits: &str, radix: u32) -> Option<Option<[u8; WORD_LEN]>> {
+    if digits.is_empty() {
+        return None;
+    }
+    let mut word = [0u8; WORD_LEN];
+    let mut overflow = false;
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix)?;
+        for byte in word.iter_mut().rev() {
+            let v = *byte as u32 * radix + carry;
+            *byte = v as u8;
+            carry = v >> 8;
+        }
+        overflow |= carry != 0;
+    }
+    Some(if overflow { None } else { Some(word) })
+}
+
+/// 2^exp as big-endian word, for exp < 256
+fn power_of_two(exp: usize) -> [u8; WORD_LEN] {
+    let mut word = [0u8; WORD_LEN];
+    if exp < 256 {
+        word[WORD_LEN - 1 - exp / 8] = 1 << (exp % 8);
+    }
+    word
+}
+
+fn bits_of(bits: &str, ty: &str) -> Result<usize, ContractError> {
+    match bits.parse::<usize>() {
+        Ok(bits) if bits > 0 && bits <= 256 && bits % 8 == 0 => Ok(bits),
+        _ => Err(invalid(format!("unknown type {}", ty))),
+    }
+}
+
+fn base_type(ty: &str) -> &str {
+    ty.split('[').next().unwrap_or(ty)
+}
+
+fn is_struct(types: &[Eip712Type], name: &str) -> bool {
+    types.iter().any(|ty| ty.name == name)
+}
+
+fn find_type<'a>(types: &'a [Eip712Type], name: &str) -> Result<&'a Eip712Type, ContractError> {
+    types
+        .iter()
+        .find(|ty| ty.name == name)
+        .ok_or_else(|| invalid(format!("unknown type {}", name)))
+}
+
+fn as_str<'a>(value: &'a Value, ty: &str) -> Result<&'a str, ContractError> {
+    match value {
+        Value::String(s) => Ok(s),
+        _ => Err(invalid(format!("expected a value of type {}", ty))),
+    }
+}
+
+fn decode_hex(input: &str) -> Result<Vec<u8>, ContractError> {
+    let hex_str = input
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid(format!("bytes must be 0x prefixed hex, got {}", input)))?;
+    hex::decode(hex_str).map_err(|err| invalid(format!("invalid hex {}: {}", input, err)))
+}
+
+fn invalid(reason: String) -> ContractError {
+    ContractError::InvalidInput {
+        msg: format!("Invalid EIP-712 typed data: {}", reason),
+    }
+}
+
+/// A JSON value. Numbers are kept as text, so no floating point is involved.
+#[derive(Debug, PartialEq)]
+enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+/// Minimal JSON parser for EIP-712 messages
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(input: &'a str) -> Result<Value, ContractError> {
+        let mut parser = Parser {
+            input: input.as_bytes(),
+            pos: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos != parser.input.len() {
+            return Err(parser.error("trailing characters"));
+        }
+        Ok(value)
+    }
+
+    fn error(&self, reason: &str) -> ContractError {
+        invalid(format!("invalid JSON at {}: {}", self.pos, reason))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), ContractError> {
+        if self.input[self.pos..].starts_with(token.as_bytes()) {
+            self.pos += token.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected {}", token)))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ContractError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.expect("null").map(|_| Value::Null),
+            Some(b't') => self.expect("true").map(|_| Value::Bool(true)),
+            Some(b'f') => self.expect("false").map(|_| Value::Bool(false)),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'[') => self.array(),
+            Some(b'{') => self.object(),
+            Some(b'-') | Some(b'0'..=b'9') => self.number(),
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, ContractError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        if let Some(b'.') | Some(b'e') | Some(b'E') = self.peek() {
+            return Err(self.error("only integer numbers are supported"));
+        }
+        let text = std::str::from_utf8(&self.input[start..self.pos]).expect("ASCII digits");
+        if text.is_empty() || text == "-" {
+            return Err(self.error("expected digits"));
+        }
+        Ok(Value::Number(text.to_string()))
+    }
+
+    fn string(&mut self) -> Result<String, ContractError> {
+        self.expect("\"")?;
+        let mut bytes = vec![];
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.pos += 1;
+                            let c = self.unicode_escape()?;
+                            let mut buf = [0u8; 4];
+                            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                            continue;
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    self.pos += 1;
+                    bytes.push(escaped as u8);
+                }
+                Some(b) if b < 0x20 => return Err(self.error("control character in string")),
+                Some(b) => {
+                    self.pos += 1;
+                    bytes.push(b);
+                }
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| self.error("invalid UTF-8"))
+    }
+
+    /// Reads the code point of a `\uXXXX` escape (after `\u`), combining surrogate pairs
+    fn unicode_escape(&mut self) -> Result<char, ContractError> {
+        let high = self.hex4()?;
+        let code = if (0xd800..0xdc00).contains(&high) {
+            self.expect("\\u")?;
+            let low = self.hex4()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(self.error("invalid surrogate pair"));
+            }
+            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+        std::char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, ContractError> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(digits)
+    }
+
+    fn array(&mut self) -> Result<Value, ContractError> {
+        self.expect("[")?;
+        let mut elements = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Value::Array(elements));
+        }
+        loop {
+            elements.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Value::Array(elements));
+                }
+                _ => return Err(self.error("expected , or ]")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, ContractError> {
+        self.expect("{")?;
+        let mut members = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(":")?;
+            members.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Value::Object(members));
+                }
+                _ => return Err(self.error("expected , or }")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Uint128;
+
+    fn field(name: &str, field_type: &str) -> Eip712Field {
+        Eip712Field {
+            name: name.to_string(),
+            field_type: field_type.to_string(),
+        }
+    }
+
+    // Example of the specification, see
+    // https://github.com/ethereum/EIPs/blob/master/assets/eip-712/Example.js
+    fn mail_types() -> Vec<Eip712Type> {
+        vec![
+            Eip712Type {
+                name: "Person".to_string(),
+                fields: vec![field("name", "string"), field("wallet", "address")],
+            },
+            Eip712Type {
+                name: "Mail".to_string(),
+                fields: vec![
+                    field("from", "Person"),
+                    field("to", "Person"),
+                    field("contents", "string"),
+                ],
+            },
+        ]
+    }
+
+    fn mail_domain() -> Eip712Domain {
+        Eip712Domain {
+            name: Some("Ether Mail".to_string()),
+            version: Some("1".to_string()),
+            chain_id: Some(Uint128(1)),
+            verifying_contract: Some("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".to_string()),
+            salt: None,
+        }
+    }
+
+    const MAIL_MESSAGE: &str = r#"{
+        "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+        "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+        "contents": "Hello, Bob!"
+    }"#;
+
+    #[test]
+    fn encode_type_works() {
+        assert_eq!(
+            encode_type(&mail_types(), "Mail").unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+        assert_eq!(
+            hex::encode(type_hash(&mail_types(), "Mail").unwrap()),
+            "a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2"
+        );
+    }
+
+    #[test]
+    fn signing_hash_works() {
+        assert_eq!(
+            hex::encode(domain_separator(&mail_domain()).unwrap()),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+        let message = Parser::parse(MAIL_MESSAGE).unwrap();
+        assert_eq!(
+            hex::encode(hash_struct(&mail_types(), "Mail", &message).unwrap()),
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+        assert_eq!(
+            hex::encode(signing_hash(&mail_domain(), &mail_types(), "Mail", MAIL_MESSAGE).unwrap()),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    #[test]
+    fn signing_hash_errors() {
+        let domain = mail_domain();
+        let types = mail_types();
+        let err = |message: &str| signing_hash(&domain, &types, "Mail", message).unwrap_err();
+
+        match err(r#"{"from": {"name": "Cow"}}"#) {
+            ContractError::InvalidInput { msg } => {
+                assert_eq!(
+                    msg,
+                    "Invalid EIP-712 typed data: missing field Person.wallet"
+                )
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+        match err(r#"{"from": 1"#) {
+            ContractError::InvalidInput { msg } => assert_eq!(
+                msg,
+                "Invalid EIP-712 typed data: invalid JSON at 10: expected , or }"
+            ),
+            err => panic!("Unexpected error: {:?}", err),
+        }
+        match signing_hash(&domain, &types, "Letter", MAIL_MESSAGE).unwrap_err() {
+            ContractError::InvalidInput { msg } => {
+                assert_eq!(msg, "Invalid EIP-712 typed data: unknown type Letter")
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn encode_integer_works() {
+        let encode = |ty: &str, value: &str| {
+            let bits = bits_of(ty.trim_start_matches('u').trim_start_matches("int"), ty).unwrap();
+            encode_integer(
+                &Value::Number(value.to_string()),
+                ty,
+                bits,
+                !ty.starts_with('u'),
+            )
+            .map(hex::encode)
+        };
+        assert_eq!(
+            encode("uint256", "1").unwrap(),
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        );
+        assert_eq!(
+            encode("uint256", "0xff").unwrap(),
+            "00000000000000000000000000000000000000000000000000000000000000ff"
+        );
+        assert_eq!(
+            encode(
+                "uint256",
+                "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+            )
+            .unwrap(),
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        );
+        assert!(encode(
+            "uint256",
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+        )
+        .is_err());
+        assert_eq!(
+            encode("uint8", "255").unwrap(),
+            "00000000000000000000000000000000000000000000000000000000000000ff"
+        );
+        assert!(encode("uint8", "256").is_err());
+        assert!(encode("uint8", "-1").is_err());
+        assert_eq!(
+            encode("int8", "-128").unwrap(),
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80"
+        );
+        assert!(encode("int8", "-129").is_err());
+        assert!(encode("int8", "128").is_err());
+        assert_eq!(
+            encode("int256", "-1").unwrap(),
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        );
+        assert!(encode("uint256", "1.5").is_err());
+        assert!(encode("uint256", "").is_err());
+    }
+
+    #[test]
+    fn encode_value_works() {
+        let types = mail_types();
+        let encode = |ty: &str, json: &str| {
+            encode_value(&types, ty, &Parser::parse(json).unwrap()).map(hex::encode)
+        };
+        assert_eq!(
+            encode("bool", "true").unwrap(),
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        );
+        assert_eq!(
+            encode("bytes4", r#""0x01020304""#).unwrap(),
+            "0102030400000000000000000000000000000000000000000000000000000000"
+        );
+        assert!(encode("bytes4", r#""0x010203""#).is_err());
+        assert_eq!(
+            encode("bytes", r#""0x""#).unwrap(),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            encode("string", r#""""#).unwrap(),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        // arrays hash the concatenated encodings of their elements
+        assert_eq!(
+            encode("uint256[]", "[]").unwrap(),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            encode("bool[2]", "[false, true]").unwrap(),
+            hex::encode(Keccak256::digest(&hex_literal::hex!(
+                "0000000000000000000000000000000000000000000000000000000000000000
+                 0000000000000000000000000000000000000000000000000000000000000001"
+            )))
+        );
+        assert!(encode("bool[3]", "[false, true]").is_err());
+        assert!(encode("uint7", "1").is_err());
+        assert!(encode("Unknown", "{}").is_err());
+    }
+
+    #[test]
+    fn parse_json_works() {
+        assert_eq!(
+            Parser::parse(r#" {"a": [1, -2, "x\"\u00e9\ud83d\ude00", null, false], "b": {}} "#)
+                .unwrap(),
+            Value::Object(vec![
+                (
+                    "a".to_string(),
+                    Value::Array(vec![
+                        Value::Number("1".to_string()),
+                        Value::Number("-2".to_string()),
+                        Value::String("x\"é😀".to_string()),
+                        Value::Null,
+                        Value::Bool(false),
+                    ])
+                ),
+                ("b".to_string(), Value::Object(vec![])),
+            ])
+        );
+        for invalid in &["", "{", "[1,]", "1.5", "\"a", "tru", "{} {}", "{\"a\" 1}"] {
+            assert!(Parser::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
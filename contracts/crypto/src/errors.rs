@@ -0,0 +1,167 @@
+use axelar_gateway_contracts::crypto::FailureReason;
+use cosmwasm_crypto::CryptoError;
+use cosmwasm_std::StdError;
+use snafu::Snafu;
+
+// Errors are reported the same way the chain's crypto API (cosmwasm-std 0.14+) reports them,
+// so results stay comparable when a contract moves from this contract to the native API.
+
+/// Failure of a cryptographic operation.
+///
+/// Codes are stable and equal to the ones of the chain's crypto API, so callers can match on
+/// them instead of on messages.
+#[derive(Snafu, Debug, Clone, PartialEq)]
+pub enum ContractError {
+    #[snafu(display(
+        "Message is longer than supported by this implementation (Limit: {}, actual length: {})",
+        limit,
+        actual
+    ))]
+    MessageTooLong { limit: usize, actual: usize },
+
+    #[snafu(display("Invalid hash format"))]
+    InvalidHashFormat,
+
+    #[snafu(display("Invalid signature format"))]
+    InvalidSignatureFormat,
+
+    #[snafu(display("Invalid public key format"))]
+    InvalidPubkeyFormat,
+
+    #[snafu(display("Invalid recovery parameter. Supported values: 0 and 1."))]
+    InvalidRecoveryParam,
+
+    #[snafu(display("Batch error: {}", msg))]
+    BatchErr { msg: String },
+
+    #[snafu(display("Generic error"))]
+    GenericErr,
+
+    // Contract specific errors, with codes from 100 up so they never collide with the
+    // crypto API's
+    /// An address that cannot be decoded, or of an unsupported type
+    #[snafu(display("{}", msg))]
+    InvalidAddress { msg: String },
+
+    /// A signature that does not follow the encoding of its scheme, e.g. of the wrong length or
+    /// with an unsupported recovery id or header byte
+    #[snafu(display("{}", msg))]
+    InvalidSignatureEncoding { msg: String },
+
+    /// Malformed or inconsistent inputs other than signatures and keys, e.g. WebAuthn client
+    /// data or EIP-712 typed data
+    #[snafu(display("{}", msg))]
+    InvalidInput { msg: String },
+
+    /// A malformed Merkle proof
+    #[snafu(display("{}", msg))]
+    InvalidProof { msg: String },
+}
+
+impl ContractError {
+    /// Stable error code
+    pub fn code(&self) -> u32 {
+        match self {
+            ContractError::MessageTooLong { .. } => 2,
+            ContractError::InvalidHashFormat => 3,
+            ContractError::InvalidSignatureFormat => 4,
+            ContractError::InvalidPubkeyFormat => 5,
+            ContractError::InvalidRecoveryParam => 6,
+            ContractError::BatchErr { .. } => 7,
+            ContractError::GenericErr => 10,
+            ContractError::InvalidAddress { .. } => 100,
+            ContractError::InvalidSignatureEncoding { .. } => 101,
+            ContractError::InvalidInput { .. } => 102,
+            ContractError::InvalidProof { .. } => 103,
+        }
+    }
+
+    /// The failure reason reported in verify responses
+    pub fn failure_reason(&self) -> FailureReason {
+        FailureReason {
+            code: self.code(),
+            message: self.to_string(),
+        }
+    }
+}
+
+impl From<CryptoError> for ContractError {
+    fn from(err: CryptoError) -> Self {
+        match err {
+            CryptoError::MessageTooLong { limit, actual, .. } => {
+                ContractError::MessageTooLong { limit, actual }
+            }
+            CryptoError::InvalidHashFormat { .. } => ContractError::InvalidHashFormat,
+            CryptoError::InvalidSignatureFormat { .. } => ContractError::InvalidSignatureFormat,
+            CryptoError::InvalidPubkeyFormat { .. } => ContractError::InvalidPubkeyFormat,
+            CryptoError::InvalidRecoveryParam { .. } => ContractError::InvalidRecoveryParam,
+            CryptoError::BatchErr { msg, .. } => ContractError::BatchErr { msg },
+            CryptoError::GenericErr { .. } => ContractError::GenericErr,
+        }
+    }
+}
+
+impl From<ContractError> for StdError {
+    fn from(err: ContractError) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
+
+/// Converts a signature verification error.
+pub fn verification_error(err: CryptoError) -> StdError {
+    StdError::generic_err(format!("Verification error: {}", ContractError::from(err)))
+}
+
+/// Converts a public key recovery error.
+pub fn recover_pubkey_error(err: CryptoError) -> StdError {
+    StdError::generic_err(format!(
+        "Recover pubkey error: {}",
+        ContractError::from(err)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contract_error_codes_match_crypto_api() {
+        let errors = vec![
+            CryptoError::invalid_hash_format(),
+            CryptoError::invalid_signature_format(),
+            CryptoError::invalid_pubkey_format(),
+            CryptoError::invalid_recovery_param(),
+            CryptoError::batch_err("mismatch"),
+            CryptoError::generic_err("failed"),
+            CryptoError::message_too_long(32, 33),
+        ];
+        for err in errors {
+            let code = err.code();
+            assert_eq!(ContractError::from(err).code(), code);
+        }
+    }
+
+    #[test]
+    fn errors_keep_crypto_api_messages() {
+        assert_eq!(
+            verification_error(CryptoError::invalid_pubkey_format()).to_string(),
+            "Generic error: Verification error: Invalid public key format"
+        );
+        assert_eq!(
+            verification_error(CryptoError::batch_err("mismatch")).to_string(),
+            "Generic error: Verification error: Batch error: mismatch"
+        );
+        assert_eq!(
+            recover_pubkey_error(CryptoError::invalid_recovery_param()).to_string(),
+            "Generic error: Recover pubkey error: Invalid recovery parameter. Supported values: 0 and 1."
+        );
+        assert_eq!(
+            recover_pubkey_error(CryptoError::generic_err("failed")).to_string(),
+            "Generic error: Recover pubkey error: Generic error"
+        );
+        assert_eq!(
+            verification_error(CryptoError::message_too_long(32, 33)).to_string(),
+            "Generic error: Verification error: Message is longer than supported by this implementation (Limit: 32, actual length: 33)"
+        );
+    }
+}
@@ -0,0 +1,520 @@
+use cosmwasm_crypto::secp256k1_recover_pubkey;
+use rlp::{DecoderError, Rlp, RlpStream};
+use sha3::{Digest, Keccak256};
+use std::convert::TryInto;
+
+use crate::errors::ContractError;
+use crate::mpt;
+use crate::secp256k1::{
+    split_recoverable_signature, RECOVERABLE_SIGNATURE_LEN, UNCOMPRESSED_PUBKEY_LEN,
+};
+
+/// Length of an Ethereum address
+pub const ADDRESS_LEN: usize = 20;
+
+/// Hashes a text message the way `eth_sign`/`personal_sign` do (EIP-191 version 0x45):
+/// `keccak256("\x19Ethereum Signed Message:\n" + len(message) + message)`.
+pub fn text_message_hash(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()));
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// Derives the Ethereum address of an uncompressed secp256k1 public key:
+/// the last 20 bytes of `keccak256(x || y)`.
+pub fn address_from_pubkey(public_key: &[u8]) -> Result<[u8; ADDRESS_LEN], ContractError> {
+    if public_key.len() != UNCOMPRESSED_PUBKEY_LEN || public_key[0] != 0x04 {
+        return Err(ContractError::InvalidPubkeyFormat);
+    }
+    let hash = Keccak256::digest(&public_key[1..]);
+    let mut address = [0u8; ADDRESS_LEN];
+    address.copy_from_slice(&hash[hash.len() - ADDRESS_LEN..]);
+    Ok(address)
+}
+
+/// Decodes a hex encoded address, with or without `0x` prefix.
+/// Decoding is case insensitive, so EIP-55 checksums are accepted but not validated.
+pub fn decode_address(input: &str) -> Result<[u8; ADDRESS_LEN], ContractError> {
+    let hex_str = input.strip_prefix("0x").unwrap_or(input);
+    let mut address = [0u8; ADDRESS_LEN];
+    hex::decode_to_slice(hex_str, &mut address).map_err(|err| ContractError::InvalidAddress {
+        msg: format!("Invalid Ethereum address {}: {}", input, err),
+    })?;
+    Ok(address)
+}
+
+/// Encodes an address in hex format with `0x` prefix and EIP-55 mixed-case checksum.
+pub fn checksum_address(address: &[u8; ADDRESS_LEN]) -> String {
+    let hex_address = hex::encode(address);
+    let hash = Keccak256::digest(hex_address.as_bytes());
+    let checksummed: String = hex_address
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0f
+            };
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+/// A legacy (pre EIP-2718) Ethereum transaction, as signed under EIP-155.
+pub struct LegacyTransaction<'a> {
+    pub nonce: u64,
+    pub gas_price: u128,
+    pub gas_limit: u128,
+    /// Recipient address, empty for contract creation.
+    pub to: &'a [u8],
+    pub value: u128,
+    pub data: &'a [u8],
+    pub chain_id: u64,
+}
+
+impl<'a> LegacyTransaction<'a> {
+    /// Hash signed by the sender: `keccak256(rlp([nonce, gas_price, gas_limit, to, value, data, chain_id, 0, 0]))`.
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut stream = RlpStream::new_list(9);
+        stream
+            .append(&self.nonce)
+            .append(&self.gas_price)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.data)
+            .append(&self.chain_id)
+            .append(&0u8)
+            .append(&0u8);
+        Keccak256::digest(&stream.out()).into()
+    }
+
+    /// Converts an EIP-155 signature into a 65-byte recoverable signature with recovery id 0/1.
+    pub fn recoverable_signature(
+        &self,
+        r: &[u8],
+        s: &[u8],
+        v: u64,
+    ) -> Result<Vec<u8>, ContractError> {
+        if r.len() != 32 || s.len() != 32 {
+            return Err(ContractError::InvalidSignatureEncoding {
+                msg: format!(
+                    "Signature values r and s must be 32 bytes long, got {} and {}",
+                    r.len(),
+                    s.len()
+                ),
+            });
+        }
+        let recovery_param = self
+            .chain_id
+            .checked_mul(2)
+            .and_then(|base| base.checked_add(35))
+            .and_then(|base| v.checked_sub(base))
+            .filter(|param| *param <= 1)
+            .ok_or_else(|| ContractError::InvalidSignatureEncoding {
+                msg: format!(
+                    "Invalid v {} for chain id {}. Expected chain_id * 2 + 35 or chain_id * 2 + 36.",
+                    v, self.chain_id
+                ),
+            })?;
+        let mut signature = Vec::with_capacity(RECOVERABLE_SIGNATURE_LEN);
+        signature.extend_from_slice(r);
+        signature.extend_from_slice(s);
+        signature.push(recovery_param as u8);
+        Ok(signature)
+    }
+
+    /// Checks that the EIP-155 signature `(r, s, v)` was made by `from`.
+    pub fn verify_sender(
+        &self,
+        from: &str,
+        r: &[u8],
+        s: &[u8],
+        v: u64,
+    ) -> Result<bool, ContractError> {
+        let from = decode_address(from)?;
+        let signature = self.recoverable_signature(r, s, v)?;
+        Ok(recover_address(&self.signing_hash(), &signature)? == from)
+    }
+}
+
+/// Recovers the address of the signer of `message_hash`.
+pub fn recover_address(
+    message_hash: &[u8],
+    signature: &[u8],
+) -> Result<[u8; ADDRESS_LEN], ContractError> {
+    let (signature, recovery_param) = split_recoverable_signature(signature)?;
+    address_from_pubkey(&secp256k1_recover_pubkey(
+        message_hash,
+        signature,
+        recovery_param,
+    )?)
+}
+
+/// Recovers the signer of `message_hash` and checks that it matches `signer_address`.
+/// The signature must use an Ethereum style recovery id `v` (27/28). Replay protected
+/// `v` values (EIP-155) are not supported here.
+pub fn verify_signer(
+    message_hash: &[u8],
+    signature: &[u8],
+    signer_address: &str,
+) -> Result<bool, ContractError> {
+    let signer_address = decode_address(signer_address)?;
+    if signature.len() != RECOVERABLE_SIGNATURE_LEN {
+        return Err(ContractError::InvalidSignatureEncoding {
+            msg: format!(
+                "Signature must be {} bytes long, got {}",
+                RECOVERABLE_SIGNATURE_LEN,
+                signature.len()
+            ),
+        });
+    }
+    let v = signature[RECOVERABLE_SIGNATURE_LEN - 1];
+    if v != 27 && v != 28 {
+        return Err(ContractError::InvalidSignatureEncoding {
+            msg: "Values of v other than 27 and 28 not supported. Replay protection (EIP-155) cannot be used here.".to_string(),
+        });
+    }
+    Ok(recover_address(message_hash, signature)? == signer_address)
+}
+
+/// Length of a logs bloom filter
+pub const BLOOM_LEN: usize = 256;
+
+/// A log emitted by a transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct Log {
+    pub address: [u8; ADDRESS_LEN],
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+}
+
+/// A transaction receipt, as committed to a block's `receiptsRoot` (Byzantium or later)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Receipt {
+    /// EIP-2718 transaction type, 0 for legacy transactions
+    pub tx_type: u8,
+    pub success: bool,
+    pub cumulative_gas_used: u64,
+    pub logs: Vec<Log>,
+}
+
+impl Receipt {
+    /// Decodes a receipt: `rlp([status, cumulative_gas_used, logs_bloom, logs])` for legacy
+    /// transactions, prefixed with the transaction type for typed (EIP-2718) transactions.
+    pub fn decode(encoded: &[u8]) -> Result<Self, ContractError> {
+        let (tx_type, body) = match encoded.split_first() {
+            Some((tx_type @ 0x01..=0x7f, body)) => (*tx_type, body),
+            Some((0xc0..=0xff, _)) => (0, encoded),
+            _ => return Err(invalid_receipt("unknown transaction type")),
+        };
+        let rlp = Rlp::new(body);
+        if rlp.item_count().map_err(receipt_rlp_error)? != 4 {
+            return Err(invalid_receipt("expected 4 fields"));
+        }
+        let success = match rlp.at(0).and_then(|status| status.data()) {
+            Ok([]) => false,
+            Ok([1]) => true,
+            _ => {
+                return Err(invalid_receipt(
+                    "invalid status (pre-Byzantium receipts are not supported)",
+                ))
+            }
+        };
+        let cumulative_gas_used = rlp.val_at(1).map_err(receipt_rlp_error)?;
+        if rlp
+            .at(2)
+            .and_then(|bloom| bloom.data())
+            .map_err(receipt_rlp_error)?
+            .len()
+            != BLOOM_LEN
+        {
+            return Err(invalid_receipt("invalid logs bloom"));
+        }
+        let logs = list_at(&rlp, 3, "logs")?
+            .iter()
+            .map(|log| decode_log(&log))
+            .collect::<Result<_, ContractError>>()?;
+        Ok(Receipt {
+            tx_type,
+            success,
+            cumulative_gas_used,
+            logs,
+        })
+    }
+}
+
+fn decode_log(log: &Rlp) -> Result<Log, ContractError> {
+    if log.item_count().map_err(receipt_rlp_error)? != 3 {
+        return Err(invalid_receipt("a log must have 3 fields"));
+    }
+    let address = log
+        .at(0)
+        .and_then(|address| address.data())
+        .map_err(receipt_rlp_error)?;
+    let address = address
+        .try_into()
+        .map_err(|_| invalid_receipt("invalid log address"))?;
+    let topics = list_at(log, 1, "log topics")?
+        .iter()
+        .map(|topic| match topic.data() {
+            Ok(topic) if topic.len() == 32 => Ok(topic.to_vec()),
+            _ => Err(invalid_receipt("invalid log topic")),
+        })
+        .collect::<Result<_, ContractError>>()?;
+    let data = log
+        .at(2)
+        .and_then(|data| data.data())
+        .map_err(receipt_rlp_error)?;
+    Ok(Log {
+        address,
+        topics,
+        data: data.to_vec(),
+    })
+}
+
+/// The list at `index` of `rlp`. Iterating over a byte string yields nothing, so a malformed
+/// receipt would otherwise decode as empty.
+fn list_at<'a>(rlp: &Rlp<'a>, index: usize, field: &str) -> Result<Rlp<'a>, ContractError> {
+    let item = rlp.at(index).map_err(receipt_rlp_error)?;
+    if !item.is_list() {
+        return Err(invalid_receipt(&format!("{} must be a list", field)));
+    }
+    Ok(item)
+}
+
+fn invalid_receipt(reason: &str) -> ContractError {
+    ContractError::InvalidInput {
+        msg: format!("Invalid receipt: {}", reason),
+    }
+}
+
+fn receipt_rlp_error(err: DecoderError) -> ContractError {
+    invalid_receipt(&format!("invalid RLP: {}", err))
+}
+
+/// Verifies the inclusion of the receipt of the transaction at `tx_index` in a block's
+/// `receiptsRoot` and decodes it. The receipts trie is keyed by `rlp(tx_index)`.
+///
+/// Returns `None` if the proof shows that there is no receipt at `tx_index`.
+pub fn verify_receipt_proof(
+    receipts_root: &[u8],
+    tx_index: u64,
+    proof: &[&[u8]],
+) -> Result<Option<Receipt>, ContractError> {
+    let key = rlp::encode(&tx_index);
+    mpt::verify_proof(receipts_root, &key, proof)?
+        .map(|receipt| Receipt::decode(&receipt))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn text_message_hash_works() {
+        // keccak256("\x19Ethereum Signed Message:\n11hello world"), see ethers.js hashMessage
+        assert_eq!(
+            text_message_hash(b"hello world"),
+            hex!("d9eba16ed0ecae432b71fe008c98cc872bb4cc214d3220a36f365326cf807d68")
+        );
+    }
+
+    #[test]
+    fn address_from_pubkey_works() {
+        // Test data from https://github.com/ethereumjs/ethereumjs-util/blob/v6.1.0/test/index.js#L404-L413
+        let public_key = hex!("043a443d8381a6798a70c6ff9304bdc8cb0163c23211d11628fae52ef9e0dca11a001cf066d56a8156fc201cd5df8a36ef694eecd258903fca7086c1fae7441e1d");
+        assert_eq!(
+            address_from_pubkey(&public_key).unwrap(),
+            hex!("2f015c60e0be116b1f0cd534704db9c92118fb6a")
+        );
+
+        // compressed keys are not supported
+        assert!(address_from_pubkey(&public_key[..33]).is_err());
+    }
+
+    #[test]
+    fn legacy_transaction_signing_hash_works() {
+        // Example from https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md#example
+        let to = hex!("3535353535353535353535353535353535353535");
+        let tx = LegacyTransaction {
+            nonce: 9,
+            gas_price: 20_000_000_000,
+            gas_limit: 21000,
+            to: &to,
+            value: 1_000_000_000_000_000_000,
+            data: &[],
+            chain_id: 1,
+        };
+        assert_eq!(
+            tx.signing_hash(),
+            hex!("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+        );
+
+        let r = hex!("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276");
+        let s = hex!("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+        let signature = tx.recoverable_signature(&r, &s, 37).unwrap();
+        assert_eq!(signature[64], 0);
+        assert_eq!(
+            recover_address(&tx.signing_hash(), &signature).unwrap(),
+            hex!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f")
+        );
+    }
+
+    #[test]
+    fn legacy_transaction_recoverable_signature_errors() {
+        let tx = LegacyTransaction {
+            nonce: 0,
+            gas_price: 0,
+            gas_limit: 0,
+            to: &[],
+            value: 0,
+            data: &[],
+            chain_id: 4,
+        };
+        let r = [1u8; 32];
+        let s = [2u8; 32];
+        assert_eq!(tx.recoverable_signature(&r, &s, 43).unwrap()[64], 0);
+        assert_eq!(tx.recoverable_signature(&r, &s, 44).unwrap()[64], 1);
+        // unprotected and wrong chain id
+        assert!(tx.recoverable_signature(&r, &s, 27).is_err());
+        assert!(tx.recoverable_signature(&r, &s, 45).is_err());
+        assert!(tx.recoverable_signature(&r, &s, 37).is_err());
+        // short r
+        assert!(tx.recoverable_signature(&r[1..], &s, 43).is_err());
+    }
+
+    #[test]
+    fn decode_address_works() {
+        let expected = hex!("12890d2cce102216644c59dae5baed380d84830c");
+        assert_eq!(
+            decode_address("0x12890D2cce102216644c59daE5baed380d84830c").unwrap(),
+            expected
+        );
+        assert_eq!(
+            decode_address("12890d2cce102216644c59dae5baed380d84830c").unwrap(),
+            expected
+        );
+        assert_eq!(
+            decode_address("0x12890D2CCE102216644C59DAE5BAED380D84830C").unwrap(),
+            expected
+        );
+        assert!(decode_address("0x12890d2cce102216644c59dae5baed380d8483").is_err());
+        assert!(decode_address("0x12890d2cce102216644c59dae5baed380d84830g").is_err());
+    }
+
+    #[test]
+    fn checksum_address_works() {
+        // Test data from https://github.com/ethereum/EIPs/blob/master/EIPS/eip-55.md
+        let addresses = [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ];
+        for address in addresses.iter() {
+            assert_eq!(
+                checksum_address(&decode_address(address).unwrap()),
+                *address
+            );
+        }
+    }
+
+    /// A log's address, topics and data
+    type TestLog<'a> = (&'a [u8], &'a [&'a [u8]], &'a [u8]);
+
+    fn encode_receipt(status: &[u8], logs: &[TestLog]) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(4);
+        stream.append(&status);
+        stream.append(&53_000u64);
+        stream.append(&vec![0u8; BLOOM_LEN]);
+        stream.begin_list(logs.len());
+        for (address, topics, data) in logs {
+            stream.begin_list(3);
+            stream.append(address);
+            stream.append_list::<&[u8], &[u8]>(topics);
+            stream.append(data);
+        }
+        stream.out().to_vec()
+    }
+
+    #[test]
+    fn receipt_decode_works() {
+        let address = [0x11u8; ADDRESS_LEN];
+        let topic = [0x22u8; 32];
+        let legacy = encode_receipt(&[1], &[(&address, &[&topic], b"data")]);
+        assert_eq!(
+            Receipt::decode(&legacy).unwrap(),
+            Receipt {
+                tx_type: 0,
+                success: true,
+                cumulative_gas_used: 53_000,
+                logs: vec![Log {
+                    address,
+                    topics: vec![topic.to_vec()],
+                    data: b"data".to_vec(),
+                }],
+            }
+        );
+
+        let mut typed = vec![0x02];
+        typed.extend(encode_receipt(&[], &[]));
+        let receipt = Receipt::decode(&typed).unwrap();
+        assert_eq!((receipt.tx_type, receipt.success), (2, false));
+        assert!(receipt.logs.is_empty());
+    }
+
+    #[test]
+    fn receipt_decode_errors() {
+        let address = [0x11u8; ADDRESS_LEN];
+        // pre-Byzantium post state root
+        assert!(Receipt::decode(&encode_receipt(&[0xaa; 32], &[])).is_err());
+        // reserved transaction type
+        let mut typed = vec![0x80];
+        typed.extend(encode_receipt(&[1], &[]));
+        assert!(Receipt::decode(&typed).is_err());
+        // short log address and topic
+        assert!(Receipt::decode(&encode_receipt(&[1], &[(&address[1..], &[], b"")])).is_err());
+        assert!(
+            Receipt::decode(&encode_receipt(&[1], &[(&address, &[&[0x22; 31]], b"")])).is_err()
+        );
+        assert!(Receipt::decode(&[]).is_err());
+    }
+
+    #[test]
+    fn receipt_decode_rejects_non_list_fields() {
+        let expect_err = |encoded: &[u8], expected: &str| match Receipt::decode(encoded) {
+            Err(ContractError::InvalidInput { msg }) => assert_eq!(msg, expected),
+            res => panic!("expected error, got {:?}", res),
+        };
+
+        // logs as a byte string
+        let mut stream = RlpStream::new_list(4);
+        stream.append(&vec![1u8]);
+        stream.append(&53_000u64);
+        stream.append(&vec![0u8; BLOOM_LEN]);
+        stream.append(&vec![0xc0u8]);
+        expect_err(&stream.out(), "Invalid receipt: logs must be a list");
+
+        // log topics as a byte string
+        let mut stream = RlpStream::new_list(4);
+        stream.append(&vec![1u8]);
+        stream.append(&53_000u64);
+        stream.append(&vec![0u8; BLOOM_LEN]);
+        stream.begin_list(1);
+        stream.begin_list(3);
+        stream.append(&vec![0x11u8; ADDRESS_LEN]);
+        stream.append(&vec![0x22u8; 32]);
+        stream.append(&b"data".to_vec());
+        expect_err(&stream.out(), "Invalid receipt: log topics must be a list");
+    }
+}
@@ -0,0 +1,51 @@
+use axelar_gateway_contracts::crypto::HashAlgorithm;
+use blake2::Blake2b;
+use ripemd160::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+use sha3::Keccak256;
+
+/// Hashes `data` with `algorithm`.
+pub fn digest(algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8> {
+    match algorithm {
+        HashAlgorithm::Sha256 => Sha256::digest(data).to_vec(),
+        HashAlgorithm::Sha512 => Sha512::digest(data).to_vec(),
+        HashAlgorithm::Keccak256 => Keccak256::digest(data).to_vec(),
+        HashAlgorithm::Ripemd160 => Ripemd160::digest(data).to_vec(),
+        HashAlgorithm::Blake2b => Blake2b::digest(data).to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digest_works() {
+        // "abc" test vectors of FIPS 180-2, Keccak, RIPEMD-160 and RFC 7693
+        let cases = [
+            (
+                HashAlgorithm::Sha256,
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ),
+            (
+                HashAlgorithm::Sha512,
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            ),
+            (
+                HashAlgorithm::Keccak256,
+                "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+            ),
+            (
+                HashAlgorithm::Ripemd160,
+                "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc",
+            ),
+            (
+                HashAlgorithm::Blake2b,
+                "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+            ),
+        ];
+        for (algorithm, expected) in cases.iter() {
+            assert_eq!(hex::encode(digest(*algorithm, b"abc")), *expected);
+        }
+    }
+}
@@ -1,4 +1,25 @@
+pub mod adr036;
+pub mod batch;
+pub mod bitcoin;
+pub mod bls;
+pub mod capabilities;
 pub mod contract;
+pub mod cosmos;
+pub mod ed25519;
+pub mod eip712;
+pub mod errors;
+pub mod ethereum;
+pub mod hash;
+pub mod merkle;
+pub mod mpt;
+pub mod schnorr;
+pub mod secp256k1;
+pub mod secp256r1;
+pub mod state;
+pub mod webauthn;
+
+#[cfg(test)]
+mod wycheproof;
 
 #[cfg(all(target_arch = "wasm32", not(feature = "library")))]
 cosmwasm_std::create_entry_points!(contract);
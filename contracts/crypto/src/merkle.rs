@@ -0,0 +1,177 @@
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+use crate::errors::ContractError;
+
+/// Length of a tree node hash
+pub const HASH_LEN: usize = 32;
+
+/// RFC 6962 leaf hash: `sha256(0x00 || leaf)`
+pub fn leaf_hash(leaf: &[u8]) -> Vec<u8> {
+    Sha256::new().chain([0x00]).chain(leaf).finalize().to_vec()
+}
+
+/// RFC 6962 inner node hash: `sha256(0x01 || left || right)`
+pub fn inner_hash(left: &[u8], right: &[u8]) -> Vec<u8> {
+    Sha256::new()
+        .chain([0x01])
+        .chain(left)
+        .chain(right)
+        .finalize()
+        .to_vec()
+}
+
+/// Verifies the inclusion of `leaf` at `index` in an RFC 6962 (Tendermint) tree of `total`
+/// leaves with the given `root`.
+///
+/// `aunts` are the sibling hashes from the leaf up to the root, as in Tendermint's
+/// `merkle.Proof`. Their number must match the position of the leaf in the tree.
+pub fn verify_rfc6962_proof(
+    leaf: &[u8],
+    index: u64,
+    total: u64,
+    aunts: &[&[u8]],
+    root: &[u8],
+) -> Result<bool, ContractError> {
+    if index >= total {
+        return Err(ContractError::InvalidProof {
+            msg: format!("Leaf index {} out of range (total {})", index, total),
+        });
+    }
+    check_hash_lengths(aunts)?;
+    let expected_aunts = proof_length(index, total);
+    if aunts.len() != expected_aunts {
+        return Err(ContractError::InvalidProof {
+            msg: format!(
+                "Invalid Merkle proof: expected {} aunts, got {}",
+                expected_aunts,
+                aunts.len()
+            ),
+        });
+    }
+    Ok(hash_from_aunts(index, total, leaf_hash(leaf), aunts) == root)
+}
+
+/// Verifies the inclusion of `leaf` in a Keccak-256 tree with sorted pairs and the given `root`,
+/// as OpenZeppelin's `MerkleProof.verify` does.
+///
+/// `leaf` is the 32-byte leaf hash itself; `proof` are the sibling hashes from the leaf up to the
+/// root. Each pair is sorted before hashing, so no positions are needed.
+pub fn verify_sorted_keccak_proof(
+    leaf: &[u8],
+    proof: &[&[u8]],
+    root: &[u8],
+) -> Result<bool, ContractError> {
+    check_hash_lengths(&[leaf])?;
+    check_hash_lengths(proof)?;
+    let computed = proof.iter().fold(leaf.to_vec(), |computed, sibling| {
+        let (a, b) = if computed.as_slice() <= *sibling {
+            (computed.as_slice(), *sibling)
+        } else {
+            (*sibling, computed.as_slice())
+        };
+        Keccak256::new().chain(a).chain(b).finalize().to_vec()
+    });
+    Ok(computed == root)
+}
+
+fn check_hash_lengths(hashes: &[&[u8]]) -> Result<(), ContractError> {
+    match hashes.iter().find(|hash| hash.len() != HASH_LEN) {
+        Some(hash) => Err(ContractError::InvalidProof {
+            msg: format!(
+                "Invalid Merkle proof: hashes must be {} bytes long, got {}",
+                HASH_LEN,
+                hash.len()
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Number of leaves in the left subtree of a tree of `total` (> 1) leaves:
+/// the largest power of two less than `total`.
+fn split_point(total: u64) -> u64 {
+    let power = 1 << (63 - total.leading_zeros());
+    if power == total {
+        power >> 1
+    } else {
+        power
+    }
+}
+
+/// Number of aunts of the leaf at `index` in a tree of `total` leaves
+fn proof_length(index: u64, total: u64) -> usize {
+    if total <= 1 {
+        return 0;
+    }
+    let left = split_point(total);
+    if index < left {
+        1 + proof_length(index, left)
+    } else {
+        1 + proof_length(index - left, total - left)
+    }
+}
+
+/// Computes the root from a leaf hash and its aunts (innermost first), following Tendermint's
+/// `computeHashFromAunts`. The number of aunts must match `proof_length(index, total)`.
+fn hash_from_aunts(index: u64, total: u64, leaf_hash: Vec<u8>, aunts: &[&[u8]]) -> Vec<u8> {
+    if total <= 1 {
+        return leaf_hash;
+    }
+    let (last, inner) = aunts.split_last().expect("aunt count checked");
+    let left = split_point(total);
+    if index < left {
+        inner_hash(&hash_from_aunts(index, left, leaf_hash, inner), last)
+    } else {
+        inner_hash(
+            last,
+            &hash_from_aunts(index - left, total - left, leaf_hash, inner),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_point_works() {
+        let cases = [(2, 1), (3, 2), (4, 2), (5, 4), (8, 4), (9, 8), (100, 64)];
+        for (total, expected) in cases.iter() {
+            assert_eq!(split_point(*total), *expected, "total {}", total);
+        }
+    }
+
+    #[test]
+    fn proof_length_works() {
+        assert_eq!(proof_length(0, 1), 0);
+        assert_eq!(proof_length(0, 2), 1);
+        assert_eq!(proof_length(2, 3), 1);
+        assert_eq!(proof_length(0, 3), 2);
+        assert_eq!(proof_length(4, 5), 1);
+        assert_eq!(proof_length(3, 8), 3);
+    }
+
+    #[test]
+    fn leaf_hash_works() {
+        // RFC 6962 hash of the empty leaf
+        assert_eq!(
+            hex::encode(leaf_hash(b"")),
+            "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d"
+        );
+        // Tendermint crypto/merkle/rfc6962_test.go
+        assert_eq!(
+            hex::encode(leaf_hash(b"L123456")),
+            "395aa064aa4c29f7010acfe3f25db9485bbd4b91897b6ad7ad547639252b4d56"
+        );
+    }
+
+    #[test]
+    fn inner_hash_works() {
+        // Tendermint crypto/merkle/rfc6962_test.go
+        assert_eq!(
+            hex::encode(inner_hash(b"N123", b"N456")),
+            "aa217fe888e47007fa15edab33c2b492a722cb106c64667fc2b044444de66bbb"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use rlp::{DecoderError, Rlp};
+use sha3::{Digest, Keccak256};
+
+use crate::errors::ContractError;
+
+/// Length of a node reference by hash
+pub const HASH_LEN: usize = 32;
+
+/// Reference to the next node on the path
+enum NodeRef<'a> {
+    /// Keccak-256 hash of a node in the proof
+    Hash(&'a [u8]),
+    /// Node shorter than 32 bytes, embedded in its parent
+    Inline(&'a [u8]),
+}
+
+/// Verifies a Merkle-Patricia trie proof of `key` against `root`, as returned by
+/// `eth_getProof` or built from the receipts of a block.
+///
+/// `proof` are the RLP encoded nodes on the path from the root to the key. Returns the value
+/// stored at `key`, or `None` if the proof shows that the key is not in the trie.
+/// Proofs that do not lead from `root` to the key are an error.
+pub fn verify_proof(
+    root: &[u8],
+    key: &[u8],
+    proof: &[&[u8]],
+) -> Result<Option<Vec<u8>>, ContractError> {
+    if root.len() != HASH_LEN {
+        return Err(invalid_proof(format!(
+            "root must be {} bytes long, got {}",
+            HASH_LEN,
+            root.len()
+        )));
+    }
+    let path: Vec<u8> = key.iter().flat_map(|b| vec![b >> 4, b & 0x0f]).collect();
+    let mut path = path.as_slice();
+    let mut nodes = proof.iter();
+    let mut next = NodeRef::Hash(root);
+
+    let value = loop {
+        let node = match next {
+            NodeRef::Hash(hash) => {
+                let node = nodes
+                    .next()
+                    .ok_or_else(|| invalid_proof("missing node".to_string()))?;
+                if Keccak256::digest(node).as_slice() != hash {
+                    return Err(invalid_proof("node hash mismatch".to_string()));
+                }
+                Rlp::new(node)
+            }
+            NodeRef::Inline(node) => Rlp::new(node),
+        };
+
+        match node.item_count().map_err(rlp_error)? {
+            // branch node: 16 children and a value
+            17 => match path.split_first() {
+                None => break Some(node.at(16).map_err(rlp_error)?.data().map_err(rlp_error)?),
+                Some((nibble, rest)) => {
+                    match node_ref(&node.at(*nibble as usize).map_err(rlp_error)?)? {
+                        Some(child) => {
+                            next = child;
+                            path = rest;
+                        }
+                        None => break None,
+                    }
+                }
+            },
+            // extension or leaf node: hex prefix encoded partial path and child or value
+            2 => {
+                let (partial, is_leaf) =
+                    decode_hex_prefix(node.at(0).map_err(rlp_error)?.data().map_err(rlp_error)?)?;
+                if !path.starts_with(&partial) {
+                    break None;
+                }
+                path = &path[partial.len()..];
+                let item = node.at(1).map_err(rlp_error)?;
+                if is_leaf {
+                    break if path.is_empty() {
+                        Some(item.data().map_err(rlp_error)?)
+                    } else {
+                        None
+                    };
+                }
+                next = node_ref(&item)?
+                    .ok_or_else(|| invalid_proof("extension node without child".to_string()))?;
+            }
+            count => {
+                return Err(invalid_proof(format!(
+                    "node must have 2 or 17 items, got {}",
+                    count
+                )))
+            }
+        }
+    };
+
+    if nodes.next().is_some() {
+        return Err(invalid_proof("unused nodes".to_string()));
+    }
+    Ok(value
+        .filter(|value| !value.is_empty())
+        .map(|value| value.to_vec()))
+}
+
+/// Reads a child reference. Returns `None` for an empty slot.
+fn node_ref<'a>(item: &Rlp<'a>) -> Result<Option<NodeRef<'a>>, ContractError> {
+    if item.is_list() {
+        return Ok(Some(NodeRef::Inline(item.as_raw())));
+    }
+    match item.data().map_err(rlp_error)? {
+        [] => Ok(None),
+        hash if hash.len() == HASH_LEN => Ok(Some(NodeRef::Hash(hash))),
+        other => Err(invalid_proof(format!(
+            "child reference must be {} bytes long, got {}",
+            HASH_LEN,
+            other.len()
+        ))),
+    }
+}
+
+/// Decodes a hex prefix encoded path into its nibbles and the leaf flag
+fn decode_hex_prefix(encoded: &[u8]) -> Result<(Vec<u8>, bool), ContractError> {
+    let (first, rest) = encoded
+        .split_first()
+        .ok_or_else(|| invalid_proof("empty partial path".to_string()))?;
+    let flag = first >> 4;
+    if flag > 3 {
+        return Err(invalid_proof(format!("invalid hex prefix flag {}", flag)));
+    }
+    let mut nibbles = Vec::with_capacity(rest.len() * 2 + 1);
+    if flag & 1 == 1 {
+        nibbles.push(first & 0x0f);
+    }
+    nibbles.extend(rest.iter().flat_map(|b| vec![b >> 4, b & 0x0f]));
+    Ok((nibbles, flag & 2 == 2))
+}
+
+fn invalid_proof(reason: String) -> ContractError {
+    ContractError::InvalidProof {
+        msg: format!("Invalid Merkle-Patricia proof: {}", reason),
+    }
+}
+
+fn rlp_error(err: DecoderError) -> ContractError {
+    invalid_proof(format!("invalid RLP: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_hex_prefix_works() {
+        // Examples from https://eth.wiki/fundamentals/patricia-tree (hex prefix encoding)
+        assert_eq!(
+            decode_hex_prefix(&[0x11, 0x23, 0x45]).unwrap(),
+            (vec![1, 2, 3, 4, 5], false)
+        );
+        assert_eq!(
+            decode_hex_prefix(&[0x00, 0x01, 0x23, 0x45]).unwrap(),
+            (vec![0, 1, 2, 3, 4, 5], false)
+        );
+        assert_eq!(
+            decode_hex_prefix(&[0x20, 0x0f, 0x1c, 0xb8]).unwrap(),
+            (vec![0, 0xf, 1, 0xc, 0xb, 8], true)
+        );
+        assert_eq!(
+            decode_hex_prefix(&[0x3f, 0x1c, 0xb8]).unwrap(),
+            (vec![0xf, 1, 0xc, 0xb, 8], true)
+        );
+        assert_eq!(decode_hex_prefix(&[0x20]).unwrap(), (vec![], true));
+        assert!(decode_hex_prefix(&[]).is_err());
+        assert!(decode_hex_prefix(&[0x40]).is_err());
+    }
+
+    // The do/dog/doge/horse trie of https://eth.wiki/fundamentals/patricia-tree.
+    // The nodes below the last branch are short enough to be embedded in it.
+    const WIKI_ROOT: &str = "5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84";
+    const WIKI_PROOF: [&str; 4] = [
+        "e216a0bd3ee507e6c67cfefca98f84be47c1bbc009315fabc4405db4ba32190374572a",
+        "f84080808080a094a9f95bd89698e4da1812e0518053813b4d5b87caaf6b3c6fa57e9e50c0ff68808080cf85206f727365887374616c6c696f6e8080808080808080",
+        "e482006fa0d43b87fdcd4217013ccc92d04662e12d36e4cc25dc690077cd821a1956fc3e36",
+        "f3808080808080de17dc808080808080c63584636f696e8080808080808080808570757070798080808080808080808476657262",
+    ];
+
+    #[test]
+    fn verify_proof_works() {
+        let root = hex::decode(WIKI_ROOT).unwrap();
+        let proof: Vec<Vec<u8>> = WIKI_PROOF
+            .iter()
+            .map(|node| hex::decode(node).unwrap())
+            .collect();
+        let proof: Vec<&[u8]> = proof.iter().map(|node| node.as_slice()).collect();
+
+        // value in a branch, in an embedded leaf and in a branch embedded in an extension
+        assert_eq!(
+            verify_proof(&root, b"do", &proof).unwrap(),
+            Some(b"verb".to_vec())
+        );
+        assert_eq!(
+            verify_proof(&root, b"doge", &proof).unwrap(),
+            Some(b"coin".to_vec())
+        );
+        assert_eq!(
+            verify_proof(&root, b"dog", &proof).unwrap(),
+            Some(b"puppy".to_vec())
+        );
+        // absent keys
+        assert_eq!(verify_proof(&root, b"dogs", &proof).unwrap(), None);
+        assert_eq!(verify_proof(&root, b"dot", &proof).unwrap(), None);
+        assert_eq!(verify_proof(&root, b"d", &proof[..3]).unwrap(), None);
+        // proofs must not contain nodes beyond the path
+        assert!(verify_proof(&root, b"d", &proof).is_err());
+        // the proof does not cover horse
+        assert!(verify_proof(&root, b"horse", &proof).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use cosmwasm_crypto::secp256k1_recover_pubkey;
+use cosmwasm_std::StdResult;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::PublicKey;
+
+use crate::errors::{recover_pubkey_error, ContractError};
+
+/// Length of a recoverable signature: 32 bytes `r`, 32 bytes `s` and the one byte `v`
+pub const RECOVERABLE_SIGNATURE_LEN: usize = 65;
+
+/// Length of a serialized compressed public key
+pub const COMPRESSED_PUBKEY_LEN: usize = 33;
+
+/// Length of a serialized uncompressed public key
+pub const UNCOMPRESSED_PUBKEY_LEN: usize = 65;
+
+/// Splits a recoverable signature into the 64-byte `r || s` signature and the recovery param.
+/// `v` may be given as 0/1 or, Ethereum style, as 27/28.
+pub fn split_recoverable_signature(signature: &[u8]) -> Result<(&[u8], u8), ContractError> {
+    if signature.len() != RECOVERABLE_SIGNATURE_LEN {
+        return Err(ContractError::InvalidSignatureEncoding {
+            msg: format!(
+                "Recoverable signature must be {} bytes long, got {}",
+                RECOVERABLE_SIGNATURE_LEN,
+                signature.len()
+            ),
+        });
+    }
+    let recovery_param = match signature[64] {
+        v @ 0..=1 => v,
+        v @ 27..=28 => v - 27,
+        v => {
+            return Err(ContractError::InvalidSignatureEncoding {
+                msg: format!(
+                    "Invalid recovery id {}. Supported values: 0, 1, 27 and 28.",
+                    v
+                ),
+            })
+        }
+    };
+    Ok((&signature[..64], recovery_param))
+}
+
+/// Recovers the uncompressed public key from a message hash and a 65-byte recoverable signature
+pub fn recover_pubkey(message_hash: &[u8], signature: &[u8]) -> StdResult<Vec<u8>> {
+    let (signature, recovery_param) = split_recoverable_signature(signature)?;
+    secp256k1_recover_pubkey(message_hash, signature, recovery_param).map_err(recover_pubkey_error)
+}
+
+/// Parses a compressed (33 bytes) or uncompressed (65 bytes) SEC1 public key, checking that it
+/// is a point on the curve.
+pub fn parse_pubkey(public_key: &[u8]) -> Result<PublicKey, ContractError> {
+    match public_key.len() {
+        COMPRESSED_PUBKEY_LEN | UNCOMPRESSED_PUBKEY_LEN => {
+            PublicKey::from_sec1_bytes(public_key).map_err(|_| ContractError::InvalidPubkeyFormat)
+        }
+        _ => Err(ContractError::InvalidPubkeyFormat),
+    }
+}
+
+/// Converts a SEC1 public key to its compressed form (33 bytes).
+pub fn compress_pubkey(public_key: &[u8]) -> Result<Vec<u8>, ContractError> {
+    Ok(parse_pubkey(public_key)?
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec())
+}
+
+/// Converts a SEC1 public key to its uncompressed form (65 bytes).
+pub fn decompress_pubkey(public_key: &[u8]) -> Result<Vec<u8>, ContractError> {
+    Ok(parse_pubkey(public_key)?
+        .to_encoded_point(false)
+        .as_bytes()
+        .to_vec())
+}
@@ -0,0 +1,62 @@
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use p256::elliptic_curve::ff::PrimeField;
+use p256::{FieldBytes, Scalar};
+
+use crate::errors::ContractError;
+use crate::secp256k1::{COMPRESSED_PUBKEY_LEN, UNCOMPRESSED_PUBKEY_LEN};
+
+/// Length of a fixed length P-256 signature: 32 bytes `r` and 32 bytes `s`
+pub const SIGNATURE_LEN: usize = 64;
+
+/// Verifies a P-256 (secp256r1) ECDSA signature over the SHA-256 digest of `message`.
+///
+/// Unlike Cosmos secp256k1 signatures, high `s` values are accepted: hardware security keys
+/// and HSMs do not normalize them.
+pub fn secp256r1_verify(
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> Result<bool, ContractError> {
+    let signature = read_signature(signature)?;
+    let public_key = read_pubkey(public_key)?;
+    Ok(public_key.verify(message, &signature).is_ok())
+}
+
+/// Converts an ASN.1 DER signature (as produced by WebAuthn authenticators) to the fixed
+/// length `r || s` format.
+pub fn signature_from_der(signature: &[u8]) -> Result<Vec<u8>, ContractError> {
+    Signature::from_asn1(signature)
+        .map(|signature| signature.as_ref().to_vec())
+        .map_err(|_| ContractError::InvalidSignatureFormat)
+}
+
+/// Parses a fixed length `r || s` signature. Both scalars must be in `[1, n - 1]`.
+fn read_signature(signature: &[u8]) -> Result<Signature, ContractError> {
+    if signature.len() != SIGNATURE_LEN {
+        return Err(ContractError::InvalidSignatureFormat);
+    }
+    let (r, s) = signature.split_at(SIGNATURE_LEN / 2);
+    let in_range = |bytes: &[u8]| match Scalar::from_repr(FieldBytes::clone_from_slice(bytes)) {
+        Some(scalar) => !bool::from(scalar.is_zero()),
+        None => false,
+    };
+    if !in_range(r) || !in_range(s) {
+        return Err(ContractError::InvalidSignatureFormat);
+    }
+    Signature::from_scalars(
+        FieldBytes::clone_from_slice(r),
+        FieldBytes::clone_from_slice(s),
+    )
+    .map_err(|_| ContractError::InvalidSignatureFormat)
+}
+
+/// Parses a compressed (33 bytes) or uncompressed (65 bytes) SEC1 public key
+fn read_pubkey(public_key: &[u8]) -> Result<VerifyingKey, ContractError> {
+    match public_key.len() {
+        COMPRESSED_PUBKEY_LEN | UNCOMPRESSED_PUBKEY_LEN => {
+            VerifyingKey::from_sec1_bytes(public_key)
+                .map_err(|_| ContractError::InvalidPubkeyFormat)
+        }
+        _ => Err(ContractError::InvalidPubkeyFormat),
+    }
+}
@@ -0,0 +1,21 @@
+use cosmwasm_std::{CanonicalAddr, ReadonlyStorage, Storage};
+use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage};
+
+pub static PREFIX_CONSUMED_DIGESTS: &[u8] = b"consumed_digests";
+
+/// Records `digest` as consumed in the namespace of `caller`.
+pub fn store_consumed_digest<S: Storage>(storage: &mut S, caller: &CanonicalAddr, digest: &[u8]) {
+    PrefixedStorage::multilevel(&[PREFIX_CONSUMED_DIGESTS, caller.as_slice()], storage)
+        .set(digest, &[1]);
+}
+
+/// True if `digest` was consumed in the namespace of `caller`.
+pub fn is_digest_consumed<S: ReadonlyStorage>(
+    storage: &S,
+    caller: &CanonicalAddr,
+    digest: &[u8],
+) -> bool {
+    ReadonlyPrefixedStorage::multilevel(&[PREFIX_CONSUMED_DIGESTS, caller.as_slice()], storage)
+        .get(digest)
+        .is_some()
+}
@@ -0,0 +1,96 @@
+use cosmwasm_std::from_slice;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::errors::ContractError;
+use crate::secp256r1::{secp256r1_verify, signature_from_der};
+
+/// Minimum length of authenticator data: rpIdHash (32 bytes), flags (1 byte) and
+/// signCount (4 bytes)
+pub const AUTHENTICATOR_DATA_MIN_LEN: usize = 37;
+
+/// Authenticator data flag: user present (UP)
+pub const FLAG_USER_PRESENT: u8 = 0x01;
+
+/// Authenticator data flag: user verified (UV)
+pub const FLAG_USER_VERIFIED: u8 = 0x04;
+
+/// `type` of the client data of an assertion
+const ASSERTION_TYPE: &str = "webauthn.get";
+
+/// The fields of clientDataJSON checked by the relying party. Other fields are ignored.
+#[derive(Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    ty: String,
+    /// base64url encoded challenge, without padding
+    challenge: String,
+    origin: String,
+}
+
+/// A WebAuthn assertion, as returned by `navigator.credentials.get()`
+pub struct Assertion<'a> {
+    pub authenticator_data: &'a [u8],
+    pub client_data_json: &'a [u8],
+    /// ASN.1 DER encoded P-256 signature
+    pub signature: &'a [u8],
+}
+
+/// The values the relying party expects in an assertion
+pub struct Expected<'a> {
+    pub challenge: &'a [u8],
+    /// Relying party id, e.g. `example.com`. The authenticator data must contain its SHA-256 hash.
+    pub rp_id: &'a str,
+    /// If set, the origin in the client data must match exactly.
+    pub origin: Option<&'a str>,
+    pub require_user_verification: bool,
+}
+
+/// Verifies a WebAuthn assertion signed with a P-256 public key (COSE algorithm ES256).
+///
+/// The assertion verifies if the client data is of type `webauthn.get` and carries the expected
+/// challenge (and origin), the authenticator data carries the hash of the relying party id and
+/// the required flags, and the signature over `authenticatorData || sha256(clientDataJSON)` is
+/// valid. Malformed client data, authenticator data, signatures or public keys are errors.
+pub fn verify_assertion(
+    assertion: &Assertion,
+    public_key: &[u8],
+    expected: &Expected,
+) -> Result<bool, ContractError> {
+    let client_data: ClientData =
+        from_slice(assertion.client_data_json).map_err(|err| ContractError::InvalidInput {
+            msg: format!("Invalid clientDataJSON: {}", err),
+        })?;
+    let authenticator_data = assertion.authenticator_data;
+    if authenticator_data.len() < AUTHENTICATOR_DATA_MIN_LEN {
+        return Err(ContractError::InvalidInput {
+            msg: format!(
+                "Authenticator data must be at least {} bytes long, got {}",
+                AUTHENTICATOR_DATA_MIN_LEN,
+                authenticator_data.len()
+            ),
+        });
+    }
+    let signature = signature_from_der(assertion.signature)?;
+
+    let challenge = base64::encode_config(expected.challenge, base64::URL_SAFE_NO_PAD);
+    let client_data_matches = client_data.ty == ASSERTION_TYPE
+        && client_data.challenge == challenge
+        && !matches!(expected.origin, Some(origin) if origin != client_data.origin);
+
+    let (rp_id_hash, flags) = (&authenticator_data[..32], authenticator_data[32]);
+    let required_flags = if expected.require_user_verification {
+        FLAG_USER_PRESENT | FLAG_USER_VERIFIED
+    } else {
+        FLAG_USER_PRESENT
+    };
+    let authenticator_data_matches = rp_id_hash
+        == Sha256::digest(expected.rp_id.as_bytes()).as_slice()
+        && flags & required_flags == required_flags;
+
+    let mut signed = authenticator_data.to_vec();
+    signed.extend_from_slice(&Sha256::digest(assertion.client_data_json));
+    let verifies = secp256r1_verify(&signed, &signature, public_key)?;
+
+    Ok(client_data_matches && authenticator_data_matches && verifies)
+}
@@ -0,0 +1,442 @@
+//! Conformance tests with the test vectors of Project Wycheproof in `testdata/wycheproof`.
+//!
+//! Every vector is run through the contract's `query`. `valid` vectors must verify, `invalid`
+//! vectors must be rejected, either by not verifying or with an error, and `acceptable` vectors
+//! may do either. The verification queries are selected by the type and curve of each test
+//! group, so new vector files of a supported type are picked up without code changes.
+//!
+//! Query types without a vector file are run with RFC vectors in the same layout.
+
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+
+use axelar_gateway_contracts::crypto::{HashFunction, InitMsg, QueryMsg, VerifyResponse};
+use cosmwasm_crypto::ECDSA_SIGNATURE_LEN;
+use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{from_binary, from_slice, Binary, Extern, HumanAddr};
+use serde::Deserialize;
+
+use crate::contract::{init, query};
+
+/// Vectors with an outcome other than expected, by file and `tcId`, with the reason. They are
+/// reported, and fail the suite once they pass, so that the list is kept up to date.
+const KNOWN_DEVIATIONS: &[(&str, u32, &str)] = &[
+    (
+        "ecdsa_secp256k1_sha256_bitcoin_test.json",
+        1,
+        "high s values are normalized before verification, as by the chain's crypto API",
+    ),
+    (
+        "ecdsa_secp256k1_sha256_bitcoin_test.json",
+        387,
+        "k256 0.7 treats s = (n - 1) / 2 as high, and rejects it after normalization",
+    ),
+];
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestFile {
+    number_of_tests: usize,
+    test_groups: Vec<TestGroup>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestGroup {
+    #[serde(rename = "type")]
+    group_type: String,
+    public_key: GroupPublicKey,
+    sha: Option<String>,
+    tests: Vec<TestVector>,
+}
+
+#[derive(Deserialize)]
+struct GroupPublicKey {
+    curve: String,
+    /// Uncompressed SEC1 point of ECDSA keys
+    uncompressed: Option<String>,
+    /// Raw EdDSA key
+    pk: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestVector {
+    tc_id: u32,
+    comment: String,
+    msg: String,
+    sig: String,
+    result: Expected,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Expected {
+    Valid,
+    Invalid,
+    Acceptable,
+}
+
+/// Outcomes of the vectors of one file and query
+#[derive(Default)]
+struct Report {
+    vectors: usize,
+    valid: usize,
+    invalid: usize,
+    acceptable: usize,
+    acceptable_verified: usize,
+    /// `tcId`s of the known deviations that occurred
+    deviations: Vec<u32>,
+    failures: Vec<String>,
+}
+
+impl Report {
+    fn record(&mut self, vector: &TestVector, verifies: bool, known_deviation: bool) {
+        self.vectors += 1;
+        let passed = match vector.result {
+            Expected::Valid => {
+                self.valid += 1;
+                verifies
+            }
+            Expected::Invalid => {
+                self.invalid += 1;
+                !verifies
+            }
+            Expected::Acceptable => {
+                self.acceptable += 1;
+                if verifies {
+                    self.acceptable_verified += 1;
+                }
+                true
+            }
+        };
+        if passed && known_deviation {
+            self.failures.push(format!(
+                "tcId {} ({}): listed as known deviation, but passes",
+                vector.tc_id, vector.comment
+            ));
+        } else if !passed && known_deviation {
+            self.deviations.push(vector.tc_id);
+        } else if !passed {
+            self.failures.push(format!(
+                "tcId {} ({}): expected {:?}, {}",
+                vector.tc_id,
+                vector.comment,
+                vector.result,
+                if verifies { "verified" } else { "rejected" }
+            ));
+        }
+    }
+}
+
+/// Builds the verification queries of a vector, named by the query they run
+type QueryBuilder = fn(&TestGroup, &TestVector) -> QueryMsg;
+
+fn queries(group: &TestGroup) -> Vec<(&'static str, QueryBuilder)> {
+    match (group.group_type.as_str(), group.public_key.curve.as_str()) {
+        ("EcdsaBitcoinVerify", "secp256k1") => {
+            assert_sha256(group);
+            vec![
+                ("verify_cosmos_signature", |group, vector| {
+                    QueryMsg::VerifyCosmosSignature {
+                        message: hex_binary(&vector.msg),
+                        signature: fixed_signature(&vector.sig),
+                        public_key: ecdsa_public_key(group),
+                    }
+                }),
+                ("verify_secp256k1_signature", |group, vector| {
+                    QueryMsg::VerifySecp256k1Signature {
+                        message: hex_binary(&vector.msg),
+                        signature: fixed_signature(&vector.sig),
+                        public_key: ecdsa_public_key(group),
+                        hash_function: HashFunction::Sha256,
+                    }
+                }),
+            ]
+        }
+        ("EcdsaVerify", "secp256r1") => {
+            assert_sha256(group);
+            vec![("verify_secp256r1_signature", |group, vector| {
+                QueryMsg::VerifySecp256r1Signature {
+                    message: hex_binary(&vector.msg),
+                    signature: fixed_signature(&vector.sig),
+                    public_key: ecdsa_public_key(group),
+                }
+            })]
+        }
+        ("EddsaVerify", "edwards25519") => {
+            vec![("verify_tendermint_signature", |group, vector| {
+                QueryMsg::VerifyTendermintSignature {
+                    message: hex_binary(&vector.msg),
+                    signature: hex_binary(&vector.sig),
+                    public_key: hex_binary(group.public_key.pk.as_ref().unwrap()),
+                }
+            })]
+        }
+        (group_type, curve) => panic!("Unsupported test group {} on {}", group_type, curve),
+    }
+}
+
+fn assert_sha256(group: &TestGroup) {
+    assert_eq!(
+        group.sha.as_deref(),
+        Some("SHA-256"),
+        "Only SHA-256 ECDSA vectors are supported"
+    );
+}
+
+fn hex_binary(data: &str) -> Binary {
+    Binary(hex::decode(data).unwrap())
+}
+
+fn ecdsa_public_key(group: &TestGroup) -> Binary {
+    hex_binary(group.public_key.uncompressed.as_ref().unwrap())
+}
+
+/// Converts a DER signature to the fixed `r || s` format of the contract. Signatures that are not
+/// strict DER, or whose integers do not fit, are passed on as is for the contract to reject.
+fn fixed_signature(der: &str) -> Binary {
+    let der = hex::decode(der).unwrap();
+    match der_to_fixed(&der) {
+        Some(signature) => Binary(signature),
+        None => Binary(der),
+    }
+}
+
+fn der_to_fixed(der: &[u8]) -> Option<Vec<u8>> {
+    let (sequence, rest) = der_element(der, 0x30)?;
+    if !rest.is_empty() {
+        return None;
+    }
+    let (r, rest) = der_element(sequence, 0x02)?;
+    let (s, rest) = der_element(rest, 0x02)?;
+    if !rest.is_empty() {
+        return None;
+    }
+    let mut signature = der_unsigned(r)?;
+    signature.extend(der_unsigned(s)?);
+    Some(signature)
+}
+
+/// Splits a DER element with `tag` and a definite, minimally encoded length off `data`
+fn der_element(data: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    if data.len() < 2 || data[0] != tag {
+        return None;
+    }
+    let (len, header) = match data[1] {
+        len @ 0..=0x7f => (len as usize, 2),
+        0x81 if data.len() > 2 && data[2] >= 0x80 => (data[2] as usize, 3),
+        _ => return None,
+    };
+    let content = data.get(header..header + len)?;
+    Some((content, &data[header + len..]))
+}
+
+/// Left pads a minimally encoded, non-negative DER integer to 32 bytes
+fn der_unsigned(integer: &[u8]) -> Option<Vec<u8>> {
+    let negative = integer.first()? & 0x80 != 0;
+    let redundant_zero = integer.len() > 1 && integer[0] == 0 && integer[1] & 0x80 == 0;
+    if negative || redundant_zero {
+        return None;
+    }
+    let integer = if integer[0] == 0 {
+        &integer[1..]
+    } else {
+        integer
+    };
+    let scalar_len = ECDSA_SIGNATURE_LEN / 2;
+    if integer.len() > scalar_len {
+        return None;
+    }
+    let mut padded = vec![0u8; scalar_len - integer.len()];
+    padded.extend_from_slice(integer);
+    Some(padded)
+}
+
+fn setup() -> Extern<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies(20, &[]);
+    init(
+        &mut deps,
+        mock_env(HumanAddr::from("creator"), &[]),
+        InitMsg {},
+    )
+    .unwrap();
+    deps
+}
+
+/// Runs all vectors of a file through every matching query
+fn run_file(path: &Path) -> Vec<(&'static str, Report)> {
+    let file_name = path.file_name().unwrap().to_str().unwrap();
+    let file: TestFile = from_slice(&fs::read(path).unwrap()).unwrap();
+    run_vectors(file_name, &file)
+}
+
+/// Runs all vectors of `file` through every matching query
+fn run_vectors(file_name: &str, file: &TestFile) -> Vec<(&'static str, Report)> {
+    let deps = setup();
+
+    let mut reports: Vec<(&'static str, Report)> = vec![];
+    for group in &file.test_groups {
+        for (name, build_query) in queries(group) {
+            let index = match reports.iter().position(|(n, _)| *n == name) {
+                Some(index) => index,
+                None => {
+                    reports.push((name, Report::default()));
+                    reports.len() - 1
+                }
+            };
+            for vector in &group.tests {
+                // malformed signatures or keys are reported as failure reason, errors count
+                // as rejection as well
+                let verifies = query(&deps, build_query(group, vector))
+                    .map(|raw| from_binary::<VerifyResponse>(&raw).unwrap().verifies)
+                    .unwrap_or(false);
+                let known_deviation = KNOWN_DEVIATIONS
+                    .iter()
+                    .any(|(file, tc_id, _)| *file == file_name && *tc_id == vector.tc_id);
+                reports[index].1.record(vector, verifies, known_deviation);
+            }
+        }
+    }
+    for (name, report) in &reports {
+        assert_eq!(report.vectors, file.number_of_tests, "{}", name);
+    }
+    reports
+}
+
+/// Prints the reports of a file and returns their failures
+fn check_reports(file_name: &str, reports: Vec<(&'static str, Report)>) -> Vec<String> {
+    let mut failures = vec![];
+    for (name, report) in reports {
+        println!(
+            "{} ({}): {} vectors, {} valid, {} invalid, {} acceptable ({} verified), {} known deviations, {} failed",
+            file_name,
+            name,
+            report.vectors,
+            report.valid,
+            report.invalid,
+            report.acceptable,
+            report.acceptable_verified,
+            report.deviations.len(),
+            report.failures.len()
+        );
+        for tc_id in &report.deviations {
+            let (_, _, reason) = KNOWN_DEVIATIONS
+                .iter()
+                .find(|(file, id, _)| *file == file_name && id == tc_id)
+                .unwrap();
+            println!("  tcId {}: {}", tc_id, reason);
+        }
+        failures.extend(
+            report
+                .failures
+                .into_iter()
+                .map(|failure| format!("{} ({}) {}", file_name, name, failure)),
+        );
+    }
+    failures
+}
+
+#[test]
+fn wycheproof_vectors() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/wycheproof");
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension() == Some(OsStr::new("json")))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "No test vectors in {}", dir.display());
+
+    let mut failures = vec![];
+    for path in paths {
+        let file_name = path.file_name().unwrap().to_str().unwrap();
+        failures.extend(check_reports(file_name, run_file(&path)));
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// RFC 6979 (A.2.5, P-256 with SHA-256) vectors in the layout of Wycheproof files, plus invalid
+/// and malleated variants of them. They run the `EcdsaVerify` query, for which no Wycheproof file
+/// is in `testdata/wycheproof` yet.
+const RFC6979_P256_VECTORS: &str = r#"{
+  "numberOfTests": 7,
+  "testGroups": [
+    {
+      "type": "EcdsaVerify",
+      "publicKey": {
+        "curve": "secp256r1",
+        "uncompressed": "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299"
+      },
+      "sha": "SHA-256",
+      "tests": [
+        { "tcId": 1, "comment": "RFC 6979 sample", "msg": "73616d706c65", "sig": "3046022100efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716022100f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8", "result": "valid" },
+        { "tcId": 2, "comment": "RFC 6979 test", "msg": "74657374", "sig": "3045022100f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d383670220019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083", "result": "valid" },
+        { "tcId": 3, "comment": "n - s", "msg": "73616d706c65", "sig": "3045022100efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf371602200834e36ad29a83bf2bc9385e491d6099c8fdf9d1ed67aa7ea5f51f93782857a9", "result": "valid" },
+        { "tcId": 4, "comment": "other message", "msg": "74657374", "sig": "3046022100efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716022100f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8", "result": "invalid" },
+        { "tcId": 5, "comment": "r and s swapped", "msg": "73616d706c65", "sig": "3046022100f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8022100efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716", "result": "invalid" },
+        { "tcId": 6, "comment": "long form length", "msg": "73616d706c65", "sig": "308146022100efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716022100f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8", "result": "invalid" },
+        { "tcId": 7, "comment": "r = 0", "msg": "73616d706c65", "sig": "3026020100022100f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8", "result": "invalid" }
+      ]
+    }
+  ]
+}"#;
+
+/// RFC 8032 (7.1) ed25519 vectors in the layout of Wycheproof files, plus invalid and malleated
+/// variants of them. They run the `EddsaVerify` query, for which no Wycheproof file is in
+/// `testdata/wycheproof` yet.
+const RFC8032_ED25519_VECTORS: &str = r#"{
+  "numberOfTests": 7,
+  "testGroups": [
+    {
+      "type": "EddsaVerify",
+      "publicKey": {
+        "curve": "edwards25519",
+        "pk": "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+      },
+      "tests": [
+        { "tcId": 1, "comment": "RFC 8032 TEST 1", "msg": "", "sig": "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b", "result": "valid" },
+        { "tcId": 2, "comment": "other message", "msg": "00", "sig": "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b", "result": "invalid" },
+        { "tcId": 3, "comment": "modified R", "msg": "", "sig": "e4564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b", "result": "invalid" },
+        { "tcId": 4, "comment": "S + L", "msg": "", "sig": "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901554c8c7872aa064e049dbb3013fbf29380d25bf5f0595bbe24655141438e7a101b", "result": "invalid" },
+        { "tcId": 5, "comment": "truncated signature", "msg": "", "sig": "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a10", "result": "invalid" }
+      ]
+    },
+    {
+      "type": "EddsaVerify",
+      "publicKey": {
+        "curve": "edwards25519",
+        "pk": "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c"
+      },
+      "tests": [
+        { "tcId": 6, "comment": "RFC 8032 TEST 2", "msg": "72", "sig": "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00", "result": "valid" },
+        { "tcId": 7, "comment": "key of TEST 1", "msg": "72", "sig": "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b", "result": "invalid" }
+      ]
+    }
+  ]
+}"#;
+
+#[test]
+fn rfc_vectors() {
+    let mut failures = vec![];
+    for (file_name, vectors, query_name) in &[
+        (
+            "rfc6979_p256",
+            RFC6979_P256_VECTORS,
+            "verify_secp256r1_signature",
+        ),
+        (
+            "rfc8032_ed25519",
+            RFC8032_ED25519_VECTORS,
+            "verify_tendermint_signature",
+        ),
+    ] {
+        let file: TestFile = from_slice(vectors.as_bytes()).unwrap();
+        let reports = run_vectors(file_name, &file);
+        let names: Vec<_> = reports.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec![query_name], "{}", file_name);
+        failures.extend(check_reports(file_name, reports));
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
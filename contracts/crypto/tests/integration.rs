@@ -15,21 +15,26 @@
 //!          let data = store.get(CONFIG_KEY).expect("no data stored");
 //!          //...
 //!      });
-//! 4. Anywhere you see init/execute(deps.as_mut(), ...) you must replace it with init/execute(&mut deps, ...)
-//! 5. Anywhere you see query(deps.as_ref(), ...) you must replace it with query(&mut deps, ...)
-//! (Use cosmwasm_vm::testing::{init, execute, query}, instead of the contract variants).
+//! 4. Calls like init/handle(&mut deps, ...) keep their arguments, but now run the contract
+//!    through the `Instance` instead of calling the contract functions directly
+//! 5. Anywhere you see query(&deps, ...) you must replace it with query(&mut deps, ...)
+//! (Use cosmwasm_vm::testing::{init, handle, query}, instead of the contract variants).
 
-use cosmwasm_std::{Binary, Response, Uint128};
+use cosmwasm_std::{from_binary, Binary, InitResponse, StdError, Uint128};
 use cosmwasm_vm::testing::{
-    instantiate, mock_env, mock_info, mock_instance, query, MockApi, MockQuerier, MockStorage,
+    init, mock_env, mock_instance, query, MockApi, MockQuerier, MockStorage,
 };
-use cosmwasm_vm::{from_slice, Instance};
+use cosmwasm_vm::Instance;
 use hex_literal::hex;
 
-use crypto_verify::msg::{InitMsg, ListVerificationsResponse, QueryMsg, VerifyResponse};
+use axelar_gateway_contracts::crypto::{
+    EntryFailure, FailureReason, InitMsg, ListVerificationsResponse, QueryMsg, VerifyBatchResponse,
+    VerifyResponse,
+};
 
 // Output of cargo wasm
-static WASM: &[u8] = include_bytes!("../target/wasm32-unknown-unknown/release/crypto_verify.wasm");
+static WASM: &[u8] =
+    include_bytes!("../../../target/wasm32-unknown-unknown/release/axelar_crypto.wasm");
 
 const CREATOR: &str = "creator";
 
@@ -43,22 +48,22 @@ const ED25519_SIGNATURE_HEX: &str = "6291d657deec24024827e69c3abe01a30ce548a2847
 const ED25519_PUBLIC_KEY_HEX: &str =
     "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025";
 
-// Signed text "connect all the things" using MyEtherWallet with private key b5b1870957d373ef0eeffecc6e4812c0fd08f554b37b233526acc331bf1544f7
-const ETHEREUM_MESSAGE: &str = "connect all the things";
-const ETHEREUM_SIGNATURE_HEX: &str = "dada130255a447ecf434a2df9193e6fbba663e4546c35c075cd6eea21d8c7cb1714b9b65a4f7f604ff6aad55fba73f8c36514a512bbbba03709b37069194f8a41b";
-const ETHEREUM_SIGNER_ADDRESS: &str = "0x12890D2cce102216644c59daE5baed380d84830c";
-
 // TEST 2 test vector from https://tools.ietf.org/html/rfc8032#section-7.1
 const ED25519_MESSAGE2_HEX: &str = "72";
 const ED25519_SIGNATURE2_HEX: &str = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
 const ED25519_PUBLIC_KEY2_HEX: &str =
     "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
 
-fn setup() -> Instance<MockApi, MockStorage, MockQuerier> {
+// Signed text "connect all the things" using MyEtherWallet with private key b5b1870957d373ef0eeffecc6e4812c0fd08f554b37b233526acc331bf1544f7
+const ETHEREUM_MESSAGE: &str = "connect all the things";
+const ETHEREUM_SIGNATURE_HEX: &str = "dada130255a447ecf434a2df9193e6fbba663e4546c35c075cd6eea21d8c7cb1714b9b65a4f7f604ff6aad55fba73f8c36514a512bbbba03709b37069194f8a41b";
+const ETHEREUM_SIGNER_ADDRESS: &str = "0x12890D2cce102216644c59daE5baed380d84830c";
+
+fn setup() -> Instance<MockStorage, MockApi, MockQuerier> {
     let mut deps = mock_instance(WASM, &[]);
     let msg = InitMsg {};
-    let info = mock_info(CREATOR, &[]);
-    let res: Response = instantiate(&mut deps, mock_env(), info, msg).unwrap();
+    let env = mock_env(CREATOR, &[]);
+    let res: InitResponse = init(&mut deps, env, msg).unwrap();
     assert_eq!(0, res.messages.len());
     deps
 }
@@ -82,10 +87,16 @@ fn cosmos_signature_verify_works() {
         public_key: Binary(public_key),
     };
 
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: true });
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: true,
+            failure_reason: None
+        }
+    );
 }
 
 #[test]
@@ -104,10 +115,16 @@ fn cosmos_signature_verify_fails() {
         public_key: Binary(public_key),
     };
 
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: false });
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: false,
+            failure_reason: None
+        }
+    );
 }
 
 #[test]
@@ -123,11 +140,19 @@ fn cosmos_signature_verify_errors() {
         signature: Binary(signature),
         public_key: Binary(public_key),
     };
-    let res = query(&mut deps, mock_env(), verify_msg);
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
+
     assert_eq!(
-        res.unwrap_err(),
-        "Verification error: Invalid public key format"
-    )
+        res,
+        VerifyResponse {
+            verifies: false,
+            failure_reason: Some(FailureReason {
+                code: 5,
+                message: "Invalid public key format".to_string(),
+            }),
+        }
+    );
 }
 
 #[test]
@@ -143,10 +168,33 @@ fn ethereum_signature_verify_works() {
         signature: signature.into(),
         signer_address: signer_address.into(),
     };
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: true });
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: true,
+            failure_reason: None
+        }
+    );
+
+    // address is matched case insensitive
+    let verify_msg = QueryMsg::VerifyEthereumText {
+        message: message.into(),
+        signature: hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap().into(),
+        signer_address: signer_address.to_lowercase(),
+    };
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
+
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: true,
+            failure_reason: None
+        }
+    );
 }
 
 #[test]
@@ -163,10 +211,16 @@ fn ethereum_signature_verify_fails_for_corrupted_message() {
         signature: signature.into(),
         signer_address: signer_address.into(),
     };
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: false });
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: false,
+            failure_reason: None
+        }
+    );
 }
 
 #[test]
@@ -184,9 +238,15 @@ fn ethereum_signature_verify_fails_for_corrupted_signature() {
         signature: signature.into(),
         signer_address: signer_address.into(),
     };
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
-    assert_eq!(res, VerifyResponse { verifies: false });
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: false,
+            failure_reason: None
+        }
+    );
 
     // Broken signature
     let signature = vec![0x1c; 65];
@@ -195,9 +255,55 @@ fn ethereum_signature_verify_fails_for_corrupted_signature() {
         signature: signature.into(),
         signer_address: signer_address.into(),
     };
-    let result = query(&mut deps, mock_env(), verify_msg);
-    let msg = result.unwrap_err();
-    assert_eq!(msg, "Recover pubkey error: Unknown error: 10");
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: false,
+            failure_reason: Some(FailureReason {
+                code: 10,
+                message: "Generic error".to_string(),
+            }),
+        }
+    );
+}
+
+#[test]
+fn ethereum_signature_verify_errors() {
+    let mut deps = setup();
+
+    // Replay protected v
+    let mut signature = hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap();
+    signature[64] = 0x2b;
+    let verify_msg = QueryMsg::VerifyEthereumText {
+        message: ETHEREUM_MESSAGE.into(),
+        signature: signature.into(),
+        signer_address: ETHEREUM_SIGNER_ADDRESS.into(),
+    };
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: false,
+            failure_reason: Some(FailureReason {
+                code: 101,
+                message: "Values of v other than 27 and 28 not supported. Replay protection (EIP-155) cannot be used here.".to_string(),
+            }),
+        }
+    );
+
+    // Invalid address
+    let verify_msg = QueryMsg::VerifyEthereumText {
+        message: ETHEREUM_MESSAGE.into(),
+        signature: hex::decode(ETHEREUM_SIGNATURE_HEX).unwrap().into(),
+        signer_address: "0x12890D2cce".into(),
+    };
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
+    assert!(!res.verifies);
+    assert_eq!(res.failure_reason.unwrap().code, 100);
 }
 
 #[test]
@@ -240,15 +346,21 @@ fn verify_ethereum_transaction_works() {
         gas_limit,
         gas_price,
         value,
-        data: data.into(),
+        data: data.to_vec().into(),
         chain_id,
         r: r.into(),
         s: s.into(),
         v,
     };
-    let raw = query(&mut deps, mock_env(), msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
-    assert_eq!(res, VerifyResponse { verifies: true });
+    let raw = query(&mut deps, msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: true,
+            failure_reason: None
+        }
+    );
 }
 
 #[test]
@@ -265,10 +377,16 @@ fn tendermint_signature_verify_works() {
         public_key: Binary(public_key),
     };
 
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: true });
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: true,
+            failure_reason: None
+        }
+    );
 }
 
 #[test]
@@ -287,10 +405,16 @@ fn tendermint_signature_verify_fails() {
         public_key: Binary(public_key),
     };
 
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: false });
+    assert_eq!(
+        res,
+        VerifyResponse {
+            verifies: false,
+            failure_reason: None
+        }
+    );
 }
 
 #[test]
@@ -306,11 +430,18 @@ fn tendermint_signature_verify_errors() {
         signature: Binary(signature),
         public_key: Binary(public_key),
     };
-    let res = query(&mut deps, mock_env(), verify_msg);
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyResponse = from_binary(&raw).unwrap();
     assert_eq!(
-        res.unwrap_err(),
-        "Verification error: Invalid public key format"
-    )
+        res,
+        VerifyResponse {
+            verifies: false,
+            failure_reason: Some(FailureReason {
+                code: 5,
+                message: "Invalid public key format".to_string(),
+            }),
+        }
+    );
 }
 
 #[test]
@@ -336,10 +467,18 @@ fn tendermint_signatures_batch_verify_works() {
         public_keys,
     };
 
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyBatchResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: true });
+    assert_eq!(
+        res,
+        VerifyBatchResponse {
+            verifies: true,
+            failed_indices: vec![],
+            failure_reason: None,
+            entry_failures: vec![],
+        }
+    );
 }
 
 #[test]
@@ -369,10 +508,18 @@ fn tendermint_signatures_batch_verify_message_multisig_works() {
         public_keys,
     };
 
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyBatchResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: true });
+    assert_eq!(
+        res,
+        VerifyBatchResponse {
+            verifies: true,
+            failed_indices: vec![],
+            failure_reason: None,
+            entry_failures: vec![],
+        }
+    );
 }
 
 #[test]
@@ -402,10 +549,18 @@ fn tendermint_signatures_batch_verify_single_public_key_works() {
         public_keys,
     };
 
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyBatchResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: true });
+    assert_eq!(
+        res,
+        VerifyBatchResponse {
+            verifies: true,
+            failed_indices: vec![],
+            failure_reason: None,
+            entry_failures: vec![],
+        }
+    );
 }
 
 #[test]
@@ -433,10 +588,18 @@ fn tendermint_signatures_batch_verify_fails() {
         public_keys: (public_keys),
     };
 
-    let raw = query(&mut deps, mock_env(), verify_msg).unwrap();
-    let res: VerifyResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyBatchResponse = from_binary(&raw).unwrap();
 
-    assert_eq!(res, VerifyResponse { verifies: false });
+    assert_eq!(
+        res,
+        VerifyBatchResponse {
+            verifies: false,
+            failed_indices: vec![1],
+            failure_reason: None,
+            entry_failures: vec![],
+        }
+    );
 }
 
 #[test]
@@ -462,11 +625,23 @@ fn tendermint_signatures_batch_verify_errors() {
         signatures,
         public_keys,
     };
-    let res = query(&mut deps, mock_env(), verify_msg);
+    let raw = query(&mut deps, verify_msg).unwrap();
+    let res: VerifyBatchResponse = from_binary(&raw).unwrap();
     assert_eq!(
-        res.unwrap_err(),
-        "Verification error: Invalid public key format"
-    )
+        res,
+        VerifyBatchResponse {
+            verifies: false,
+            failed_indices: vec![1],
+            failure_reason: None,
+            entry_failures: vec![EntryFailure {
+                index: 1,
+                failure_reason: FailureReason {
+                    code: 5,
+                    message: "Invalid public key format".to_string(),
+                },
+            }],
+        }
+    );
 }
 
 #[test]
@@ -475,14 +650,24 @@ fn query_works() {
 
     let query_msg = QueryMsg::ListVerificationSchemes {};
 
-    let raw = query(&mut deps, mock_env(), query_msg).unwrap();
-    let res: ListVerificationsResponse = from_slice(&raw).unwrap();
+    let raw = query(&mut deps, query_msg).unwrap();
+    let res: ListVerificationsResponse = from_binary(&raw).unwrap();
 
     assert_eq!(
         res,
         ListVerificationsResponse {
             verification_schemes: vec![
                 "secp256k1".into(),
+                "secp256k1_batch".into(),
+                "adr036".into(),
+                "secp256r1".into(),
+                "webauthn".into(),
+                "ethereum_text".into(),
+                "ethereum_transaction".into(),
+                "eip712".into(),
+                "bitcoin_message".into(),
+                "bip340".into(),
+                "bls12_381".into(),
                 "ed25519".into(),
                 "ed25519_batch".into()
             ]
@@ -10,17 +10,19 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::state::{
-    read_config, read_contract_address, store_config, store_contract_address,
-    verifying_key_from_base64_str, Config,
+    read_config, read_contract_address, store_config, store_contract_address, Config,
 };
 
 use axelar_gateway_contracts::crypto::{
-    InitMsg as CryptoInitMsg, QueryMsg as CryptoQueryMsg, VerifyResponse as CryptoVerifyResponse,
+    CapabilitiesResponse as CryptoCapabilitiesResponse, HashFunction, HashMode,
+    InitMsg as CryptoInitMsg, KeyEncoding, ListVerificationsResponse, QueryMsg as CryptoQueryMsg,
+    VerifyResponse as CryptoVerifyResponse,
 };
 use axelar_gateway_contracts::gateway::{
-    CanSendResponse, ConfigResponse, HandleMsg, InitMsg, QueryMsg, ContractAddressResponse
+    CanSendResponse, ConfigResponse, HandleMsg, InitMsg, QueryMsg, ContractAddressResponse,
+    KeyType, PublicKey,
 };
-use sha3::{Digest, Keccak256};
+use sha2::{Digest, Sha256};
 
 pub static ATTR_NEW_OWNER: &str = "new_owner";
 pub static ATTR_PREV_OWNER: &str = "previous_owner";
@@ -31,14 +33,21 @@ pub fn init<S: Storage, A: Api, Q: Querier>(
     _env: Env,
     msg: InitMsg,
 ) -> StdResult<InitResponse> {
+    let key_type = msg.key_type.unwrap_or(KeyType::Secp256k1);
+    must_support_owner_signatures(deps, &msg.crypto_contract_addr, key_type)?;
     let mut cfg = Config {
         crypto_contract_addr: deps.api.canonical_address(&msg.crypto_contract_addr)?,
         nonce: 0,
         mutable: true,
         owner: CanonicalAddr::default(),
         public_key: vec![],
+        key_type,
     };
-    cfg.update_owner(deps.api.canonical_address(&msg.owner)?, &msg.public_key)?;
+    cfg.update_owner(
+        deps.api.canonical_address(&msg.owner)?,
+        &msg.public_key,
+        key_type,
+    )?;
 
     store_config(&mut deps.storage, &cfg)?;
     Ok(InitResponse {
@@ -64,9 +73,17 @@ pub fn handle<S: Storage, A: Api, Q: Querier>(
         } => handle_execute_signed(deps, env, msgs, register, sig),
         HandleMsg::Execute { msgs, register } => handle_execute(deps, env, msgs, register),
         HandleMsg::Register { name } => handle_register_contract(deps, env, name),
-        HandleMsg::UpdateOwner { owner, public_key } => {
-            handle_update_owner(deps, env, owner, public_key)
-        }
+        HandleMsg::UpdateOwner {
+            owner,
+            public_key,
+            key_type,
+        } => handle_update_owner(
+            deps,
+            env,
+            owner,
+            public_key,
+            key_type.unwrap_or(KeyType::Secp256k1),
+        ),
         HandleMsg::Freeze {} => handle_freeze(deps, env),
     }
 }
@@ -100,14 +117,19 @@ pub fn handle_update_owner<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
     env: Env,
     owner: HumanAddr,
-    public_key: String,
+    public_key: PublicKey,
+    key_type: KeyType,
 ) -> StdResult<HandleResponse> {
     must_be_owner(&deps, &env)?;
     must_not_be_frozen(&deps, &env)?;
     let mut cfg = read_config(&deps.storage)?;
     let prev_owner = deps.api.human_address(&cfg.owner)?;
 
-    cfg.update_owner(deps.api.canonical_address(&owner)?, &public_key)?;
+    if key_type != cfg.key_type {
+        let crypto_contract_addr = deps.api.human_address(&cfg.crypto_contract_addr)?;
+        must_support_owner_signatures(deps, &crypto_contract_addr, key_type)?;
+    }
+    cfg.update_owner(deps.api.canonical_address(&owner)?, &public_key, key_type)?;
     store_config(&mut deps.storage, &cfg)?;
 
     let mut res = HandleResponse::default();
@@ -158,10 +180,21 @@ where
 {
     must_not_be_frozen(&deps, &env)?;
 
-    if !verify_signed_by_owner(&deps, &msgs, sig)? {
-        Err(StdError::unauthorized())
+    let mut cfg = read_config(&deps.storage)?;
+    let verification = verify_signed_by_owner(&deps, cfg.nonce, &msgs, sig.clone())?;
+    if !verification.verifies {
+        // tell a replayed batch apart from a bad signature
+        if verification.failure_reason.is_none() && cfg.nonce > 0 {
+            let used_nonce = cfg.nonce - 1;
+            if verify_signed_by_owner(&deps, used_nonce, &msgs, sig)?.verifies {
+                return Err(StdError::generic_err(format!(
+                    "Replayed owner signature: already used with nonce {}",
+                    used_nonce
+                )));
+            }
+        }
+        Err(signature_rejected(verification))
     } else {
-        let mut cfg = read_config(&deps.storage)?;
         cfg.increment_nonce();
         store_config(&mut deps.storage, &cfg)?;
 
@@ -213,55 +246,150 @@ pub fn store_registration_intent<S: Storage, A: Api, Q: Querier>(
 
 fn verify_signed_by_owner<S: Storage, A: Api, Q: Querier, T>(
     deps: &Extern<S, A, Q>,
+    nonce: u64,
     msgs: &Vec<CosmosMsg<T>>,
     sig: Vec<u8>,
-) -> StdResult<bool>
+) -> StdResult<CryptoVerifyResponse>
 where
     T: Clone + fmt::Debug + PartialEq + JsonSchema + Serialize,
 {
     let cfg = read_config(&deps.storage)?;
+    let verify_msg = match cfg.key_type {
+        // the digest is already hashed, so the crypto contract verifies it as is
+        KeyType::Secp256k1 => CryptoQueryMsg::VerifySecp256k1Signature {
+            message: Binary::from(digest_message_batch(nonce, msgs)?),
+            signature: Binary::from(sig),
+            public_key: Binary::from(cfg.public_key),
+            hash_function: HashFunction::None,
+        },
+        // P-256 verification always hashes the message with SHA-256
+        KeyType::Secp256r1 => CryptoQueryMsg::VerifySecp256r1Signature {
+            message: Binary::from(serialize_message_batch(nonce, msgs)?),
+            signature: Binary::from(sig),
+            public_key: Binary::from(cfg.public_key),
+        },
+    };
 
-    // serialize cosmos messages into base64 encoded json
-    let mut bytes = msgs
-        .into_iter()
-        .map(|msg| to_vec(&msg))
-        .collect::<Result<Vec<_>, _>>()?
-        .concat();
+    deps.querier
+        .query(&cosmwasm_std::QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: deps.api.human_address(&cfg.crypto_contract_addr)?,
+            msg: to_binary(&verify_msg)?,
+        }))
+}
 
-    // append the nonce and calculate the message digest
-    bytes.extend_from_slice(&cfg.nonce.to_be_bytes());
-    let digest = Keccak256::digest(bytes.as_slice());
+/// Checks that the crypto contract verifies owner signatures with keys of `key_type`:
+/// secp256k1 signatures of a prehashed message batch digest, or P-256 signatures of the
+/// SHA-256 hashed message batch, by a compressed public key.
+///
+/// Crypto contracts instantiated before they stored their cw2 contract version cannot report
+/// capabilities. For those, the names of their verification schemes are checked instead.
+fn must_support_owner_signatures<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    crypto_contract_addr: &HumanAddr,
+    key_type: KeyType,
+) -> StdResult<()> {
+    let request = cosmwasm_std::QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: crypto_contract_addr.clone(),
+        msg: to_binary(&CryptoQueryMsg::Capabilities {})?,
+    });
+    let capabilities: CryptoCapabilitiesResponse = match deps.querier.query(&request) {
+        Ok(capabilities) => capabilities,
+        Err(_) => return must_list_scheme(deps, crypto_contract_addr, key_type),
+    };
 
-    let verify_msg = CryptoQueryMsg::VerifyCosmosSignature {
-        message: Binary::from(digest.as_slice()),
-        signature: Binary::from(sig),
-        public_key: Binary::from(cfg.public_key),
+    let (hash_mode, description) = match key_type {
+        KeyType::Secp256k1 => (HashMode::Prehashed, "prehashed secp256k1"),
+        KeyType::Secp256r1 => (HashMode::Sha256, "SHA-256 secp256r1"),
     };
+    let supported = capabilities.schemes.iter().any(|scheme| {
+        scheme.scheme == key_type.name()
+            && scheme.key_encodings.contains(&KeyEncoding::Sec1Compressed)
+            && scheme.hash_modes.contains(&hash_mode)
+    });
+    if !supported {
+        return Err(StdError::generic_err(format!(
+            "crypto contract {} ({} {}) does not support {} signatures",
+            crypto_contract_addr,
+            capabilities.version.contract,
+            capabilities.version.version,
+            description
+        )));
+    }
+    Ok(())
+}
 
-    let res: CryptoVerifyResponse =
-        deps.querier
-            .query(&cosmwasm_std::QueryRequest::Wasm(WasmQuery::Smart {
-                contract_addr: deps.api.human_address(&cfg.crypto_contract_addr)?,
-                msg: to_binary(&verify_msg)?,
-            }))?;
+/// Checks that a crypto contract without contract version lists the verification scheme of
+/// `key_type`.
+fn must_list_scheme<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    crypto_contract_addr: &HumanAddr,
+    key_type: KeyType,
+) -> StdResult<()> {
+    let schemes: ListVerificationsResponse = deps
+        .querier
+        .query(&cosmwasm_std::QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: crypto_contract_addr.clone(),
+            msg: to_binary(&CryptoQueryMsg::ListVerificationSchemes {})?,
+        }))
+        .map_err(|err| {
+            StdError::generic_err(format!(
+                "failed to query capabilities of crypto contract {}: {}",
+                crypto_contract_addr, err
+            ))
+        })?;
+    if !schemes
+        .verification_schemes
+        .iter()
+        .any(|scheme| scheme == key_type.name())
+    {
+        return Err(StdError::generic_err(format!(
+            "crypto contract {} (no contract version) does not support {} signatures",
+            crypto_contract_addr,
+            key_type.name()
+        )));
+    }
+    Ok(())
+}
 
-    Ok(res.verifies)
+/// Error for a rejected owner signature. Malformed signatures are reported with the reason
+/// given by the crypto contract; well formed signatures by another key are unauthorized.
+fn signature_rejected(verification: CryptoVerifyResponse) -> StdError {
+    match verification.failure_reason {
+        Some(reason) => StdError::generic_err(format!(
+            "Invalid owner signature: {} (code {})",
+            reason.message, reason.code
+        )),
+        None => StdError::unauthorized(),
+    }
 }
 
-pub fn digest_message_batch<T>(nonce: u64, msgs: &Vec<CosmosMsg<T>>) -> StdResult<Vec<u8>>
+/// Serializes a batch of messages for signing: the JSON encoded messages concatenated,
+/// followed by the big-endian nonce. The owner signs these bytes with a standard
+/// ECDSA/SHA-256 signer, as Cosmos SDK keys (secp256k1) and HSMs (P-256) do.
+pub fn serialize_message_batch<T>(nonce: u64, msgs: &[CosmosMsg<T>]) -> StdResult<Vec<u8>>
 where
     T: Clone + fmt::Debug + PartialEq + JsonSchema + Serialize,
 {
-    // serialize cosmos messages into base64 encoded json
+    // serialize cosmos messages into json
     let mut bytes = msgs
-        .into_iter()
-        .map(|msg| to_vec(&msg))
+        .iter()
+        .map(to_vec)
         .collect::<Result<Vec<_>, _>>()?
         .concat();
 
-    // append the nonce and calculate the message digest
+    // append the nonce
     bytes.extend_from_slice(&nonce.to_be_bytes());
-    Ok(Keccak256::digest(bytes.as_slice()).to_vec())
+    Ok(bytes)
+}
+
+/// SHA-256 digest of the serialized message batch, which is what the owner's signature
+/// is verified against.
+pub fn digest_message_batch<T>(nonce: u64, msgs: &[CosmosMsg<T>]) -> StdResult<Vec<u8>>
+where
+    T: Clone + fmt::Debug + PartialEq + JsonSchema + Serialize,
+{
+    let bytes = serialize_message_batch(nonce, msgs)?;
+    Ok(Sha256::digest(bytes.as_slice()).to_vec())
 }
 
 pub fn handle_freeze<S: Storage, A: Api, Q: Querier>(
@@ -298,6 +426,7 @@ fn query_config<S: Storage, A: Api, Q: Querier>(
     Ok(ConfigResponse {
         owner: deps.api.human_address(&cfg.owner)?,
         public_key: cfg.public_key,
+        key_type: cfg.key_type,
         crypto_contract_addr: deps.api.human_address(&cfg.crypto_contract_addr)?,
         nonce: cfg.nonce,
         mutable: cfg.mutable,
@@ -312,8 +441,13 @@ fn query_can_send<S: Storage, A: Api, Q: Querier, T>(
 where
     T: Clone + fmt::Debug + PartialEq + JsonSchema + Serialize,
 {
+    let nonce = read_config(&deps.storage)?.nonce;
+    let verification = verify_signed_by_owner(&deps, nonce, &msgs, sig)?;
     Ok(CanSendResponse {
-        can_send: verify_signed_by_owner(&deps, &msgs, sig)?,
+        can_send: verification.verifies,
+        reason: verification
+            .failure_reason
+            .map(|reason| format!("{} (code {})", reason.message, reason.code)),
     })
 }
 
@@ -336,11 +470,12 @@ fn query_contract_address<S: Storage, A: Api, Q: Querier>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{App, AppDeps, ContractWrapper};
     use axelar_crypto::contract as crypto_contract;
+    use axelar_token::contract as token_contract;
+    use axelar_token_factory::contract as token_factory_contract;
     use cosmwasm_std::{
-        coins, from_binary,
-        testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage},
-        Binary, CosmosMsg, HumanAddr, StdError, WasmMsg,
+        log as log_attr, Binary, CosmosMsg, HumanAddr, StdError, Uint128, WasmMsg,
     };
     use k256::{
         ecdsa::{signature::Signer, signature::Verifier, Signature, SigningKey, VerifyingKey},
@@ -348,152 +483,716 @@ mod tests {
         CompressedPoint, EncodedPoint,
     };
 
-    use axelar_gateway_contracts::crypto::{
-        InitMsg as CryptoInitMsg, QueryMsg as CryptoQueryMsg,
-        VerifyResponse as CryptoVerifyResponse,
-    };
+    use axelar_gateway_contracts::crypto::InitMsg as CryptoInitMsg;
     use axelar_gateway_contracts::gateway::{
         CanSendResponse, ConfigResponse, HandleMsg, InitMsg, QueryMsg,
     };
+    use axelar_gateway_contracts::hook::InitHook;
+    use axelar_gateway_contracts::token::{
+        HandleMsg as TokenHandleMsg, QueryMsg as TokenQueryMsg,
+    };
+    use axelar_gateway_contracts::token_factory::{
+        HandleMsg as TokenFactoryHandleMsg, InitMsg as TokenFactoryInitMsg,
+        QueryMsg as TokenFactoryQueryMsg, TokenAddressResponse,
+    };
+    use cw20::{BalanceResponse, MinterResponse, TokenInfoResponse};
     use rand_core::OsRng;
-    use sha3::{Digest, Keccak256};
 
-    use crate::state::{base64_str_from_sec1_bytes, verifying_key_from_base64_str};
+    use crate::state::{compressed_public_key, verifying_key_from_public_key, TERRA_PUBKEY_HRP};
+    use bech32::ToBase32;
 
     const USE_POINT_COMPRESSION: bool = true;
     const CANONICAL_LENGTH: usize = 20;
 
-    // default localterra public key
-    const PUBLIC_KEY_BASE64_COMPRESSED: &str = "";
+    struct Deployment {
+        app: App,
+        owner: HumanAddr,
+        crypto: HumanAddr,
+        gateway: HumanAddr,
+        priv_key: SigningKey,
+        token_code_id: u64,
+        token_factory_code_id: u64,
+    }
 
-    fn setup_gateway(
-        mut crypto_addr: HumanAddr,
-    ) -> (
-        Extern<MockStorage, MockApi, MockQuerier>,
-        Env,
-        HumanAddr,
-        CompressedPoint,
-        SigningKey,
-    ) {
-        let mut deps = mock_dependencies(CANONICAL_LENGTH, &[]);
+    // Deploys the crypto and gateway contracts and stores the token and token factory code.
+    fn deploy_gateway() -> Deployment {
+        let mut app = App::new();
+        let crypto_code_id = app.store_code(Box::new(ContractWrapper::new(
+            crypto_contract::init,
+            crypto_contract::handle,
+            crypto_contract::query,
+        )));
+        let gateway_code_id = app.store_code(Box::new(ContractWrapper::new(init, handle, query)));
+        let token_code_id = app.store_code(Box::new(ContractWrapper::new(
+            token_contract::init,
+            token_contract::handle,
+            token_contract::query,
+        )));
+        let token_factory_code_id = app.store_code(Box::new(ContractWrapper::new(
+            token_factory_contract::init,
+            token_factory_contract::handle,
+            token_factory_contract::query,
+        )));
+
+        let owner = HumanAddr::from("axelar");
+        let priv_key = SigningKey::random(&mut OsRng);
+        let pub_key = VerifyingKey::from(&priv_key).to_bytes();
+
+        let crypto = app
+            .instantiate(crypto_code_id, &owner, &CryptoInitMsg {})
+            .unwrap();
+        let gateway = app
+            .instantiate(
+                gateway_code_id,
+                &owner,
+                &InitMsg {
+                    owner: owner.clone(),
+                    public_key: PublicKey::Hex(hex::encode(pub_key.as_slice())),
+                    crypto_contract_addr: crypto.clone(),
+                    key_type: None,
+                },
+            )
+            .unwrap();
+
+        Deployment {
+            app,
+            owner,
+            crypto,
+            gateway,
+            priv_key,
+            token_code_id,
+            token_factory_code_id,
+        }
+    }
+
+    fn wasm_execute<T: Serialize>(contract_addr: &HumanAddr, msg: &T) -> CosmosMsg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.clone(),
+            msg: to_binary(msg).unwrap(),
+            send: vec![],
+        })
+    }
+
+    #[test]
+    fn pubkey_format() {
+        // This test serves as guide for producing a correctly encoded public key for
+        // the InitMsg{} and HandleMsg::UpdateOwner{} messages.
 
         let priv_key = SigningKey::random(&mut OsRng); // Serialize with `::to_bytes()`
         let verifying_key = VerifyingKey::from(&priv_key);
-        let pub_key = verifying_key.to_bytes(); // SEC-1 encoded compressed point
-
-        let axelar = HumanAddr::from("axelar");
-        if crypto_addr.len() == 0 {
-            crypto_addr = HumanAddr::from("crypto_contract");
+        let compressed = verifying_key.to_bytes();
+        let uncompressed = verifying_key.to_encoded_point(false);
+
+        let mut amino = vec![0xeb, 0x5a, 0xe9, 0x87, 0x21];
+        amino.extend_from_slice(compressed.as_slice());
+
+        let public_keys = vec![
+            PublicKey::Base64(Binary::from(compressed.as_slice()).to_base64()),
+            PublicKey::Base64(Binary::from(uncompressed.as_bytes()).to_base64()),
+            PublicKey::Hex(hex::encode(compressed.as_slice())),
+            PublicKey::Hex(format!("0x{}", hex::encode(uncompressed.as_bytes()))),
+            PublicKey::Bech32(bech32::encode(TERRA_PUBKEY_HRP, amino.to_base32()).unwrap()),
+        ];
+
+        // every format maps to the same compressed SEC-1 bytes
+        for public_key in public_keys {
+            let vk_import = verifying_key_from_public_key(&public_key).unwrap();
+            assert_eq!(vk_import.to_bytes(), compressed, "{:?}", public_key);
         }
+    }
 
-        // instantiate the contract
-        let msg = InitMsg {
-            owner: axelar.clone(),
-            public_key: base64_str_from_sec1_bytes(&pub_key),
-            crypto_contract_addr: crypto_addr.clone(),
+    #[test]
+    fn pubkey_format_errors() {
+        let priv_key = SigningKey::random(&mut OsRng);
+        let compressed = VerifyingKey::from(&priv_key).to_bytes();
+
+        let assert_err = |public_key: PublicKey, expected: &str| {
+            match verifying_key_from_public_key(&public_key) {
+                Err(StdError::GenericErr { msg, .. }) => assert!(
+                    msg.starts_with(expected),
+                    "unexpected error for {:?}: {}",
+                    public_key,
+                    msg
+                ),
+                res => panic!("expected error for {:?}, got {:?}", public_key, res),
+            }
         };
 
-        let env = mock_env(axelar.clone(), &[]);
-        let res = init(&mut deps, env.clone(), msg).unwrap();
+        assert_err(
+            PublicKey::Base64("not base64!".into()),
+            "failed to decode base64 public key",
+        );
+        assert_err(
+            PublicKey::Hex("0xzz".into()),
+            "failed to decode hex public key",
+        );
+        assert_err(
+            PublicKey::Hex(format!("0x0x{}", hex::encode(compressed.as_slice()))),
+            "failed to decode hex public key",
+        );
+        assert_err(
+            PublicKey::Bech32("terrapub1invalid".into()),
+            "failed to decode bech32 public key",
+        );
+
+        // wrong human readable part
+        let mut amino = vec![0xeb, 0x5a, 0xe9, 0x87, 0x21];
+        amino.extend_from_slice(compressed.as_slice());
+        assert_err(
+            PublicKey::Bech32(bech32::encode("cosmospub", amino.to_base32()).unwrap()),
+            "failed to decode bech32 public key: expected prefix terrapub",
+        );
+
+        // missing amino prefix
+        assert_err(
+            PublicKey::Bech32(bech32::encode(TERRA_PUBKEY_HRP, compressed.to_base32()).unwrap()),
+            "failed to decode bech32 public key: not an amino encoded secp256k1 key",
+        );
+
+        // decodes, but is not a curve point
+        assert_err(
+            PublicKey::Hex(hex::encode([0x05; 33])),
+            "hex public key is not a valid SEC1-encoded secp256k1 point (33 bytes)",
+        );
+
+        // the legacy base64 encoded JSON byte array is rejected
+        let legacy = cosmwasm_std::to_binary(compressed.as_slice()).unwrap().to_base64();
+        assert_err(
+            PublicKey::Base64(legacy),
+            "base64 public key is not a valid SEC1-encoded secp256k1 point",
+        );
+    }
 
-        // ensure expected config
-        let expected = ConfigResponse {
-            owner: axelar.clone(),
-            public_key: pub_key.to_vec(),
-            crypto_contract_addr: crypto_addr.clone(),
-            nonce: 0u64,
-            mutable: true,
+    #[test]
+    fn initialization() {
+        let Deployment {
+            app,
+            owner,
+            crypto,
+            gateway,
+            priv_key,
+            ..
+        } = deploy_gateway();
+
+        let cfg: ConfigResponse = app.query(&gateway, &QueryMsg::<Empty>::Config {}).unwrap();
+        assert_eq!(
+            cfg,
+            ConfigResponse {
+                owner,
+                public_key: VerifyingKey::from(&priv_key).to_bytes().to_vec(),
+                key_type: KeyType::Secp256k1,
+                crypto_contract_addr: crypto,
+                nonce: 0u64,
+                mutable: true,
+            }
+        );
+    }
+
+    #[test]
+    fn initialization_requires_crypto_capabilities() {
+        let mut app = App::new();
+        let gateway_code_id = app.store_code(Box::new(ContractWrapper::new(init, handle, query)));
+        // a crypto contract without secp256k1 support
+        let crypto_code_id = app.store_code(Box::new(ContractWrapper::new(
+            crypto_contract::init,
+            crypto_contract::handle,
+            |deps: &AppDeps, msg: CryptoQueryMsg| match msg {
+                CryptoQueryMsg::Capabilities {} => {
+                    let mut res = crypto_contract::query_capabilities(deps)?;
+                    res.schemes.retain(|scheme| scheme.scheme != "secp256k1");
+                    to_binary(&res)
+                }
+                msg => crypto_contract::query(deps, msg),
+            },
+        )));
+
+        let owner = HumanAddr::from("axelar");
+        let pub_key = VerifyingKey::from(&SigningKey::random(&mut OsRng)).to_bytes();
+        let crypto = app
+            .instantiate(crypto_code_id, &owner, &CryptoInitMsg {})
+            .unwrap();
+        let init_msg = |crypto_contract_addr: &HumanAddr| InitMsg {
+            owner: owner.clone(),
+            public_key: PublicKey::Hex(hex::encode(pub_key.as_slice())),
+            crypto_contract_addr: crypto_contract_addr.clone(),
+            key_type: None,
         };
-        assert_eq!(query_config(&deps).unwrap(), expected);
 
-        assert_eq!(0, res.messages.len());
-        (deps, env, axelar, pub_key, priv_key)
+        match app.instantiate(gateway_code_id, &owner, &init_msg(&crypto)) {
+            Err(StdError::GenericErr { msg, .. }) => assert_eq!(
+                msg,
+                format!(
+                    "crypto contract {} (crates.io:axelar-crypto crypto-verify-v2) does not support prehashed secp256k1 signatures",
+                    crypto
+                )
+            ),
+            res => panic!("must reject the crypto contract, got {:?}", res),
+        }
+
+        match app.instantiate(gateway_code_id, &owner, &init_msg(&HumanAddr::from("nobody"))) {
+            Err(StdError::GenericErr { msg, .. }) => assert!(
+                msg.starts_with("failed to query capabilities of crypto contract nobody"),
+                "{}",
+                msg
+            ),
+            res => panic!("must reject a missing crypto contract, got {:?}", res),
+        }
+
+        // P-256 owner keys are verified with the secp256r1 scheme
+        let p256_key =
+            p256::ecdsa::VerifyingKey::from(&p256::ecdsa::SigningKey::random(&mut OsRng));
+        let p256_init_msg = InitMsg {
+            public_key: PublicKey::Hex(hex::encode(p256_key.to_encoded_point(true).as_bytes())),
+            key_type: Some(KeyType::Secp256r1),
+            ..init_msg(&crypto)
+        };
+        app.instantiate(gateway_code_id, &owner, &p256_init_msg)
+            .unwrap();
     }
 
-    fn setup_crypto() -> Extern<MockStorage, MockApi, MockQuerier> {
-        let mut deps = mock_dependencies(CANONICAL_LENGTH, &[]);
-        let res = crypto_contract::init(
-            &mut deps,
-            mock_env(HumanAddr::from("addr01"), &[]),
-            CryptoInitMsg {},
-        )
-        .unwrap();
-        assert_eq!(0, res.messages.len());
-        deps
+    #[test]
+    fn initialization_with_crypto_contract_without_version() {
+        let mut app = App::new();
+        let gateway_code_id = app.store_code(Box::new(ContractWrapper::new(init, handle, query)));
+        // crypto contracts instantiated before the cw2 contract version was stored
+        let crypto_code_id = app.store_code(Box::new(ContractWrapper::new(
+            |_: &mut AppDeps, _: Env, _: CryptoInitMsg| Ok(InitResponse::default()),
+            crypto_contract::handle,
+            crypto_contract::query,
+        )));
+        let ed25519_crypto_code_id = app.store_code(Box::new(ContractWrapper::new(
+            |_: &mut AppDeps, _: Env, _: CryptoInitMsg| Ok(InitResponse::default()),
+            crypto_contract::handle,
+            |deps: &AppDeps, msg: CryptoQueryMsg| match msg {
+                CryptoQueryMsg::ListVerificationSchemes {} => {
+                    to_binary(&ListVerificationsResponse {
+                        verification_schemes: vec!["ed25519".into()],
+                    })
+                }
+                msg => crypto_contract::query(deps, msg),
+            },
+        )));
+
+        let owner = HumanAddr::from("axelar");
+        let pub_key = VerifyingKey::from(&SigningKey::random(&mut OsRng)).to_bytes();
+        let init_msg = |crypto_contract_addr: &HumanAddr| InitMsg {
+            owner: owner.clone(),
+            public_key: PublicKey::Hex(hex::encode(pub_key.as_slice())),
+            crypto_contract_addr: crypto_contract_addr.clone(),
+            key_type: None,
+        };
+
+        let crypto = app
+            .instantiate(crypto_code_id, &owner, &CryptoInitMsg {})
+            .unwrap();
+        let gateway = app
+            .instantiate(gateway_code_id, &owner, &init_msg(&crypto))
+            .unwrap();
+        let cfg: ConfigResponse = app.query(&gateway, &QueryMsg::<Empty>::Config {}).unwrap();
+        assert_eq!(cfg.crypto_contract_addr, crypto);
+
+        let crypto = app
+            .instantiate(ed25519_crypto_code_id, &owner, &CryptoInitMsg {})
+            .unwrap();
+        match app.instantiate(gateway_code_id, &owner, &init_msg(&crypto)) {
+            Err(StdError::GenericErr { msg, .. }) => assert_eq!(
+                msg,
+                format!(
+                    "crypto contract {} (no contract version) does not support secp256k1 signatures",
+                    crypto
+                )
+            ),
+            res => panic!("must reject the crypto contract, got {:?}", res),
+        }
     }
 
     #[test]
-    fn pubkey_format() {
-        // This test serves as guide for producing a correctly encoded public key for
-        // the InitMsg{} and HandleMsg::UpdateOwner{} messages.
+    fn execute_signed() {
+        let Deployment {
+            mut app,
+            gateway,
+            priv_key,
+            ..
+        } = deploy_gateway();
+        let anyone = HumanAddr::from("anyone");
+
+        // the gateway proxies an empty batch to itself, which succeeds every time
+        let noop = vec![wasm_execute(
+            &gateway,
+            &HandleMsg::<Empty>::Execute {
+                msgs: vec![],
+                register: vec![],
+            },
+        )];
+        let freeze = vec![wasm_execute(&gateway, &HandleMsg::<Empty>::Freeze {})];
+        let sign = |nonce: u64, messages: &[CosmosMsg]| -> Vec<u8> {
+            let batch = serialize_message_batch(nonce, messages).unwrap();
+            let sig: Signature = priv_key.sign(batch.as_slice());
+            sig.as_ref().to_vec()
+        };
+        let execute_signed = |messages: &[CosmosMsg], sig: Vec<u8>| HandleMsg::ExecuteSigned {
+            msgs: messages.to_vec(),
+            sig,
+            register: vec![],
+        };
 
-        let priv_key = SigningKey::random(&mut OsRng); // Serialize with `::to_bytes()`
-        let verifying_key = VerifyingKey::from(&priv_key);
-        let pub_key = verifying_key.to_bytes();
+        // signature over the wrong nonce
+        match app.execute(&anyone, &gateway, &execute_signed(&noop, sign(1, &noop))) {
+            Err(StdError::Unauthorized { .. }) => {}
+            res => panic!("must return unauthorized error, got {:?}", res),
+        }
 
-        // check base64 string representation maps correctly to SEC-1 bytes
-        let pk_str = base64_str_from_sec1_bytes(&pub_key);
-        println!("pk_str {}", pk_str);
-        let vk_import = verifying_key_from_base64_str(&pk_str).unwrap();
+        let can_send: CanSendResponse = app
+            .query(
+                &gateway,
+                &QueryMsg::<Empty>::CanSend {
+                    msgs: noop.clone(),
+                    sig: sign(0, &noop),
+                },
+            )
+            .unwrap();
+        assert!(can_send.can_send);
+
+        app.execute(&anyone, &gateway, &execute_signed(&noop, sign(0, &noop)))
+            .unwrap();
+
+        let cfg: ConfigResponse = app.query(&gateway, &QueryMsg::<Empty>::Config {}).unwrap();
+        assert_eq!(cfg.nonce, 1);
+        assert!(cfg.mutable);
+
+        // replay
+        match app.execute(&anyone, &gateway, &execute_signed(&noop, sign(0, &noop))) {
+            Err(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "Replayed owner signature: already used with nonce 0")
+            }
+            res => panic!("must return replay error, got {:?}", res),
+        }
 
-        // to compressed point
-        let imported_pub_key = vk_import.to_bytes();
+        // the same batch signed with the current nonce executes again
+        app.execute(&anyone, &gateway, &execute_signed(&noop, sign(1, &noop)))
+            .unwrap();
 
-        // back to base64 str
-        let imp_str = base64_str_from_sec1_bytes(&imported_pub_key);
-        assert_eq!(imported_pub_key, pub_key);
-        assert_eq!(imp_str, pk_str);
+        app.execute(
+            &anyone,
+            &gateway,
+            &execute_signed(&freeze, sign(2, &freeze)),
+        )
+        .unwrap();
 
-        // test key created using terra.js
-        /* let verifying_key = verifying_key_from_base64_str(PUBLIC_KEY_BASE64_COMPRESSED).unwrap();
-        let imp_str = base64_str_from_sec1_bytes(&verifying_key.to_bytes());
-        assert_eq!(PUBLIC_KEY_BASE64_COMPRESSED, imp_str); */
+        let cfg: ConfigResponse = app.query(&gateway, &QueryMsg::<Empty>::Config {}).unwrap();
+        assert_eq!(cfg.nonce, 3);
+        assert!(!cfg.mutable);
     }
 
     #[test]
-    fn initialization() {
-        setup_gateway(HumanAddr::default());
+    fn execute_signed_rejects_malformed_signature() {
+        let Deployment {
+            mut app,
+            gateway,
+            priv_key,
+            ..
+        } = deploy_gateway();
+        let anyone = HumanAddr::from("anyone");
+
+        let messages = vec![wasm_execute(&gateway, &HandleMsg::<Empty>::Freeze {})];
+        let batch = serialize_message_batch(0, &messages).unwrap();
+        let sig: Signature = priv_key.sign(batch.as_slice());
+        let truncated = sig.as_ref()[..63].to_vec();
+
+        let can_send: CanSendResponse = app
+            .query(
+                &gateway,
+                &QueryMsg::<Empty>::CanSend {
+                    msgs: messages.clone(),
+                    sig: truncated.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            can_send,
+            CanSendResponse {
+                can_send: false,
+                reason: Some("Invalid signature format (code 4)".to_string()),
+            }
+        );
+
+        let execute_signed = HandleMsg::ExecuteSigned {
+            msgs: messages,
+            sig: truncated,
+            register: vec![],
+        };
+        match app.execute(&anyone, &gateway, &execute_signed) {
+            Err(StdError::GenericErr { msg, .. }) => assert_eq!(
+                msg,
+                "Invalid owner signature: Invalid signature format (code 4)"
+            ),
+            res => panic!("must return invalid signature error, got {:?}", res),
+        }
     }
 
     #[test]
-    fn execute_signed() {
-        let (mut deps, gateway_env, owner, pub_key, priv_key) = setup_gateway(HumanAddr::default());
+    fn execute_signed_with_p256_owner() {
+        let Deployment {
+            mut app,
+            owner,
+            gateway,
+            priv_key,
+            ..
+        } = deploy_gateway();
+        let anyone = HumanAddr::from("anyone");
+
+        let noop = vec![wasm_execute(
+            &gateway,
+            &HandleMsg::<Empty>::Execute {
+                msgs: vec![],
+                register: vec![],
+            },
+        )];
+        let batch = |nonce: u64| serialize_message_batch(nonce, &noop).unwrap();
+        let execute_signed = |sig: Vec<u8>| HandleMsg::ExecuteSigned {
+            msgs: noop.clone(),
+            sig,
+            register: vec![],
+        };
+
+        // hand the gateway over to a P-256 key, e.g. one held by an HSM
+        let p256_priv_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let p256_pub_key = p256::ecdsa::VerifyingKey::from(&p256_priv_key)
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        app.execute(
+            &owner,
+            &gateway,
+            &HandleMsg::<Empty>::UpdateOwner {
+                owner: owner.clone(),
+                public_key: PublicKey::Base64(Binary::from(p256_pub_key.as_slice()).to_base64()),
+                key_type: Some(KeyType::Secp256r1),
+            },
+        )
+        .unwrap();
 
-        let nonce = 0u64;
+        let cfg: ConfigResponse = app.query(&gateway, &QueryMsg::<Empty>::Config {}).unwrap();
+        assert_eq!(cfg.key_type, KeyType::Secp256r1);
+        assert_eq!(
+            cfg.public_key,
+            p256::ecdsa::VerifyingKey::from(&p256_priv_key)
+                .to_encoded_point(true)
+                .as_bytes()
+                .to_vec()
+        );
+
+        // the previous secp256k1 key no longer signs for the gateway
+        let sig: Signature = priv_key.sign(batch(0).as_slice());
+        match app.execute(&anyone, &gateway, &execute_signed(sig.as_ref().to_vec())) {
+            Err(StdError::Unauthorized { .. }) => {}
+            res => panic!("must return unauthorized error, got {:?}", res),
+        }
 
-        let exec_msg = CosmosMsg::Wasm::<Empty>(WasmMsg::Execute {
-            contract_addr: gateway_env.contract.address,
-            msg: to_binary(&HandleMsg::<Empty>::Freeze {}).unwrap(),
+        let sig: p256::ecdsa::Signature = p256_priv_key.sign(batch(0).as_slice());
+        let can_send: CanSendResponse = app
+            .query(
+                &gateway,
+                &QueryMsg::<Empty>::CanSend {
+                    msgs: noop.clone(),
+                    sig: sig.as_ref().to_vec(),
+                },
+            )
+            .unwrap();
+        assert!(can_send.can_send);
+        app.execute(&anyone, &gateway, &execute_signed(sig.as_ref().to_vec()))
+            .unwrap();
+
+        let cfg: ConfigResponse = app.query(&gateway, &QueryMsg::<Empty>::Config {}).unwrap();
+        assert_eq!(cfg.nonce, 1);
+
+        // decodes, but is not a P-256 point
+        match compressed_public_key(&PublicKey::Hex(hex::encode([0x05; 33])), KeyType::Secp256r1) {
+            Err(StdError::GenericErr { msg, .. }) => assert_eq!(
+                msg,
+                "hex public key is not a valid SEC1-encoded secp256r1 point (33 bytes)"
+            ),
+            res => panic!("expected error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn deploy_register_mint_withdraw_burn() {
+        let Deployment {
+            mut app,
+            owner,
+            gateway,
+            token_code_id,
+            token_factory_code_id,
+            ..
+        } = deploy_gateway();
+        let factory_name = String::from("token_factory");
+
+        // deploy the token factory through the gateway, which registers it by name
+        let instantiate_factory = CosmosMsg::<Empty>::Wasm(WasmMsg::Instantiate {
+            code_id: token_factory_code_id,
+            msg: to_binary(&TokenFactoryInitMsg {
+                owner: gateway.clone(),
+                token_code_id,
+                init_hook: Some(InitHook {
+                    contract_addr: gateway.clone(),
+                    msg: to_binary(&HandleMsg::<Empty>::Register {
+                        name: factory_name.clone(),
+                    })
+                    .unwrap(),
+                }),
+            })
+            .unwrap(),
             send: vec![],
+            label: None,
         });
+        app.execute(
+            &owner,
+            &gateway,
+            &HandleMsg::Execute {
+                msgs: vec![instantiate_factory],
+                register: vec![factory_name.clone()],
+            },
+        )
+        .unwrap();
 
-        let messages = vec![exec_msg.clone(), exec_msg.clone(), exec_msg.clone()];
+        let res: ContractAddressResponse = app
+            .query(
+                &gateway,
+                &QueryMsg::<Empty>::ContractAddress { name: factory_name },
+            )
+            .unwrap();
+        let factory = res.contract_addr;
+        assert_ne!(factory, HumanAddr::default());
+
+        // deploy a token through the factory, which registers it by symbol
+        let symbol = String::from("satoshi");
+        let deploy_token = wasm_execute(
+            &factory,
+            &TokenFactoryHandleMsg::DeployToken {
+                name: String::from("Satoshi"),
+                symbol: symbol.clone(),
+                decimals: 8,
+                cap: Uint128(1000000),
+            },
+        );
+        app.execute(
+            &owner,
+            &gateway,
+            &HandleMsg::Execute {
+                msgs: vec![deploy_token],
+                register: vec![],
+            },
+        )
+        .unwrap();
 
-        let digest = digest_message_batch(nonce, &messages).unwrap();
-        let sig: Signature = priv_key.sign(digest.as_slice());
+        let res: TokenAddressResponse = app
+            .query(&factory, &TokenFactoryQueryMsg::TokenAddress { symbol })
+            .unwrap();
+        let token = res.token_addr;
+        assert_ne!(token, HumanAddr::default());
+
+        let minter: MinterResponse = app.query(&token, &TokenQueryMsg::Minter {}).unwrap();
+        assert_eq!(minter.minter, gateway);
+
+        // mint to a user
+        let user = HumanAddr::from("user");
+        let amount = Uint128(100);
+        let mint = wasm_execute(
+            &token,
+            &TokenHandleMsg::Mint {
+                recipient: user.clone(),
+                amount,
+            },
+        );
+        app.execute(
+            &owner,
+            &gateway,
+            &HandleMsg::Execute {
+                msgs: vec![mint],
+                register: vec![],
+            },
+        )
+        .unwrap();
 
-        let msg = HandleMsg::ExecuteSigned {
-            msgs: messages,
-            sig: Vec::<u8>::from(sig.as_ref()),
-            register: vec![],
+        let balance = |app: &App, address: &HumanAddr| -> Uint128 {
+            let res: BalanceResponse = app
+                .query(
+                    &token,
+                    &TokenQueryMsg::Balance {
+                        address: address.clone(),
+                    },
+                )
+                .unwrap();
+            res.balance
         };
+        assert_eq!(balance(&app, &user), amount);
 
-        // simulate query from gateway contract for now
-        let verify_msg = CryptoQueryMsg::VerifyCosmosSignature {
-            message: Binary::from(digest.as_slice()),
-            signature: Binary::from(sig.as_ref()),
-            public_key: Binary::from(pub_key.as_ref()),
+        // only the gateway can mint
+        let mint = TokenHandleMsg::Mint {
+            recipient: user.clone(),
+            amount,
         };
-        let crypto_deps = setup_crypto();
-        let raw =
-            crypto_contract::query::<MockStorage, MockApi, MockQuerier>(&crypto_deps, verify_msg)
-                .unwrap();
-        let res: CryptoVerifyResponse = from_binary(&raw).unwrap();
-        assert_eq!(res, CryptoVerifyResponse { verifies: true });
-        /*
-               // todo: how to simulate contract address in env?
-               let env = mock_env(HumanAddr::from("anyone"), &[]);
-               let rest = handle(&mut deps, env, msg).unwrap();
-        */
+        match app.execute(&owner, &token, &mint) {
+            Err(StdError::Unauthorized { .. }) => {}
+            res => panic!("must return unauthorized error, got {:?}", res),
+        }
+
+        // withdraw moves the tokens to the gateway
+        let btc_addr = HumanAddr::from("tb1qw99lg2um87u0gxx4c8k9f9h8ka0tcjcmjk92np");
+        let log = app
+            .execute(
+                &user,
+                &token,
+                &TokenHandleMsg::Withdraw {
+                    recipient: btc_addr.clone(),
+                    amount,
+                },
+            )
+            .unwrap();
+        assert!(log.contains(&log_attr("to", btc_addr)));
+        assert_eq!(balance(&app, &user), Uint128::zero());
+        assert_eq!(balance(&app, &gateway), amount);
+
+        // the gateway burns the withdrawn tokens
+        let burn = wasm_execute(&token, &TokenHandleMsg::Burn { amount });
+        app.execute(
+            &owner,
+            &gateway,
+            &HandleMsg::Execute {
+                msgs: vec![burn],
+                register: vec![],
+            },
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &gateway), Uint128::zero());
+
+        let info: TokenInfoResponse = app.query(&token, &TokenQueryMsg::TokenInfo {}).unwrap();
+        assert_eq!(info.total_supply, Uint128::zero());
+    }
+
+    #[test]
+    fn failed_messages_are_rolled_back() {
+        let Deployment {
+            mut app,
+            owner,
+            gateway,
+            ..
+        } = deploy_gateway();
+
+        // the registration intent is stored before the unknown contract fails
+        let msg = HandleMsg::Execute {
+            msgs: vec![wasm_execute(&HumanAddr::from("unknown"), &HandleMsg::<Empty>::Freeze {})],
+            register: vec![String::from("name")],
+        };
+        assert!(app.execute(&owner, &gateway, &msg).is_err());
+
+        let res: StdResult<ContractAddressResponse> = app.query(
+            &gateway,
+            &QueryMsg::<Empty>::ContractAddress {
+                name: String::from("name"),
+            },
+        );
+        assert!(res.is_err());
     }
 }
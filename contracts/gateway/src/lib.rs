@@ -1,6 +1,9 @@
 pub mod contract;
 pub mod state;
 
+#[cfg(test)]
+mod testing;
+
 #[cfg(target_arch = "wasm32")]
 mod wasm {
     use super::contract;
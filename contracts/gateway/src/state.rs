@@ -1,11 +1,14 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::{Binary, CanonicalAddr, HumanAddr, ReadonlyStorage, StdError, StdResult, Storage, from_binary, to_binary};
+use cosmwasm_std::{Binary, CanonicalAddr, HumanAddr, ReadonlyStorage, StdError, StdResult, Storage};
 use cosmwasm_storage::{PrefixedStorage, ReadonlyPrefixedStorage, ReadonlySingleton, Singleton, singleton, singleton_read};
-use k256::{ecdsa::VerifyingKey, CompressedPoint};
+use k256::ecdsa::VerifyingKey;
+use bech32::FromBase32;
 use cosmwasm_crypto::{ECDSA_PUBKEY_MAX_LEN};
 
+use axelar_gateway_contracts::gateway::{KeyType, PublicKey};
+
 /// Length of a serialized compressed public key
 const ECDSA_COMPRESSED_PUBKEY_LEN: usize = 33;
 
@@ -17,8 +20,11 @@ pub static PREFIX_CONTRACT_ADDRESSES: &[u8] = b"contract_addresses";
 pub struct Config {
     // contract owner address
     pub owner: CanonicalAddr,
-    // k256::CompressedPoint Compressed SEC1-encoded secp256k1 (K-256) point.
+    // Compressed SEC1-encoded point on the curve of key_type
     pub public_key: Vec<u8>,
+    // curve of the owner's public key, secp256k1 for configs stored before it was added
+    #[serde(default = "default_key_type")]
+    pub key_type: KeyType,
 
     pub nonce: u64, // prevent replay of meta-transactions
 
@@ -29,21 +35,84 @@ pub struct Config {
     pub mutable: bool,
 }
 
-/// Convert a SEC1-encoded compressed secp256k1 point (public key bytes) to a base64 string
-pub fn base64_str_from_sec1_bytes(pub_key: &CompressedPoint) -> String {
-    let vec = &pub_key.to_vec();
-    let bin = to_binary(pub_key.as_ref()).unwrap();
-    bin.to_base64()
+fn default_key_type() -> KeyType {
+    KeyType::Secp256k1
+}
+
+/// Amino prefix of a secp256k1 public key: the `tendermint/PubKeySecp256k1` type prefix
+/// followed by the length of the compressed key.
+const AMINO_SECP256K1_PUBKEY_PREFIX: [u8; 5] = [0xeb, 0x5a, 0xe9, 0x87, 0x21];
+
+/// Human readable part of a bech32 encoded Terra account public key
+pub const TERRA_PUBKEY_HRP: &str = "terrapub";
+
+/// Decode a public key using the format it is tagged with and check that the result is a
+/// valid SEC1-encoded secp256k1 point.
+pub fn verifying_key_from_public_key(public_key: &PublicKey) -> StdResult<VerifyingKey> {
+    let bytes = sec1_bytes_from_public_key(public_key)?;
+    VerifyingKey::from_sec1_bytes(bytes.as_slice()).map_err(|_| {
+        StdError::generic_err(format!(
+            "{} public key is not a valid SEC1-encoded secp256k1 point ({} bytes)",
+            public_key.format(),
+            bytes.len()
+        ))
+    })
 }
 
-/// Convert a base64 string representing a SEC1-encoded compressed secp256k1 point to a verifying
-/// key.
-pub fn verifying_key_from_base64_str(pk_str: &str) -> StdResult<VerifyingKey> {
-    let bin = Binary::from_base64(pk_str)?;
-    let key_vec: Vec<u8> = from_binary(&bin)?;
-    match VerifyingKey::from_sec1_bytes(key_vec.as_slice()) {
-        Ok(vk) => Ok(vk),
-        Err(err) => return Err(StdError::generic_err("failed to deserialize public key")),
+/// Decode an owner public key and return its compressed SEC1 bytes, checking that it is a
+/// valid point on the curve of `key_type`.
+pub fn compressed_public_key(public_key: &PublicKey, key_type: KeyType) -> StdResult<Vec<u8>> {
+    match key_type {
+        KeyType::Secp256k1 => Ok(verifying_key_from_public_key(public_key)?
+            .to_bytes()
+            .to_vec()),
+        KeyType::Secp256r1 => {
+            let bytes = sec1_bytes_from_public_key(public_key)?;
+            let verifying_key = p256::ecdsa::VerifyingKey::from_sec1_bytes(bytes.as_slice())
+                .map_err(|_| {
+                    StdError::generic_err(format!(
+                        "{} public key is not a valid SEC1-encoded secp256r1 point ({} bytes)",
+                        public_key.format(),
+                        bytes.len()
+                    ))
+                })?;
+            Ok(verifying_key.to_encoded_point(true).as_bytes().to_vec())
+        }
+    }
+}
+
+/// Decode a public key to its SEC1 bytes without validating the point.
+pub fn sec1_bytes_from_public_key(public_key: &PublicKey) -> StdResult<Vec<u8>> {
+    let decode_err = |err: String| {
+        StdError::generic_err(format!(
+            "failed to decode {} public key: {}",
+            public_key.format(),
+            err
+        ))
+    };
+
+    match public_key {
+        PublicKey::Base64(encoded) => Binary::from_base64(encoded)
+            .map(|bin| bin.0)
+            .map_err(|err| decode_err(err.to_string())),
+        PublicKey::Hex(encoded) => {
+            let encoded = encoded.strip_prefix("0x").unwrap_or(encoded);
+            hex::decode(encoded).map_err(|err| decode_err(err.to_string()))
+        }
+        PublicKey::Bech32(encoded) => {
+            let (hrp, data) = bech32::decode(encoded).map_err(|err| decode_err(err.to_string()))?;
+            if hrp != TERRA_PUBKEY_HRP {
+                return Err(decode_err(format!(
+                    "expected prefix {}, got {}",
+                    TERRA_PUBKEY_HRP, hrp
+                )));
+            }
+            let bytes = Vec::<u8>::from_base32(&data).map_err(|err| decode_err(err.to_string()))?;
+            if !bytes.starts_with(&AMINO_SECP256K1_PUBKEY_PREFIX) {
+                return Err(decode_err("not an amino encoded secp256k1 key".to_string()));
+            }
+            Ok(bytes[AMINO_SECP256K1_PUBKEY_PREFIX.len()..].to_vec())
+        }
     }
 }
 
@@ -80,12 +149,15 @@ pub fn read_contract_address<S: Storage>(
 }
 
 impl Config {
-    pub fn update_owner(&mut self, owner: CanonicalAddr, public_key: &String) -> StdResult<()> {
+    pub fn update_owner(
+        &mut self,
+        owner: CanonicalAddr,
+        public_key: &PublicKey,
+        key_type: KeyType,
+    ) -> StdResult<()> {
         // sanitize pub_key
-        let verifying_key = verifying_key_from_base64_str(public_key)?;
-
-        // cfg.update_owner(deps.api.canonical_address(&msg.owner)?, pub_key.to_bytes().to_vec());
-        self.public_key = verifying_key.to_bytes().to_vec();
+        self.public_key = compressed_public_key(public_key, key_type)?;
+        self.key_type = key_type;
         self.owner = owner;
         
         // @nb messages could be replayed if owner was set to a previous owner
@@ -0,0 +1,402 @@
+//! In-process test harness that wires several contracts together.
+//!
+//! `App` keeps one storage per contract instance and dispatches the `WasmMsg::Execute` and
+//! `WasmMsg::Instantiate` messages returned by a contract to the target contract, depth first
+//! and in order, with the calling contract as sender. `WasmQuery::Smart` and `WasmQuery::Raw`
+//! requests are answered from the same set of instances, so the gateway can query the crypto
+//! contract and init hooks call back into the contract that requested the instantiation.
+//!
+//! Every call made through `App` is atomic: if the call or any message it dispatches fails,
+//! the storage of every contract is rolled back and newly instantiated contracts are dropped.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+use cosmwasm_std::{
+    from_slice, testing::mock_env, to_binary, Binary, CosmosMsg, Empty, Env, Extern, HandleResponse,
+    HumanAddr, InitResponse, LogAttribute, Order, Querier, QuerierResult, QueryRequest,
+    ReadonlyStorage, StdError, StdResult, Storage, SystemError, WasmMsg, WasmQuery, KV,
+};
+use cosmwasm_std::testing::MockApi;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Human addresses are padded to this length by `MockApi`
+pub const CANONICAL_LENGTH: usize = 20;
+
+pub type AppDeps = Extern<AppStorage, MockApi, AppQuerier>;
+
+/// Storage of a single contract instance. Clones share the same underlying data, which lets
+/// the querier read a contract's state while that contract is executing.
+#[derive(Clone, Default)]
+pub struct AppStorage(Rc<RefCell<BTreeMap<Vec<u8>, Vec<u8>>>>);
+
+impl ReadonlyStorage for AppStorage {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.0.borrow().get(key).cloned()
+    }
+
+    fn range<'a>(
+        &'a self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = KV> + 'a> {
+        let data = self.0.borrow();
+        // `Option::is_none_or` is too new for the toolchains CosmWasm 0.10 supports.
+        #[allow(clippy::unnecessary_map_or)]
+        let in_range = |key: &Vec<u8>| {
+            start.map_or(true, |start| key.as_slice() >= start)
+                && end.map_or(true, |end| key.as_slice() < end)
+        };
+        let mut items: Vec<KV> = data
+            .iter()
+            .filter(|(key, _)| in_range(key))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        if let Order::Descending = order {
+            items.reverse();
+        }
+        Box::new(items.into_iter())
+    }
+}
+
+impl Storage for AppStorage {
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.0.borrow_mut().insert(key.to_vec(), value.to_vec());
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.0.borrow_mut().remove(key);
+    }
+}
+
+/// Type erased entry points of a contract
+pub trait Contract {
+    fn init(&self, deps: &mut AppDeps, env: Env, msg: &[u8]) -> StdResult<InitResponse>;
+
+    fn handle(&self, deps: &mut AppDeps, env: Env, msg: &[u8]) -> StdResult<HandleResponse>;
+
+    fn query(&self, deps: &AppDeps, msg: &[u8]) -> StdResult<Binary>;
+}
+
+/// Wraps a contract's `init`, `handle` and `query` functions, deserializing their messages
+pub struct ContractWrapper<I, H, Q> {
+    init_fn: fn(&mut AppDeps, Env, I) -> StdResult<InitResponse>,
+    handle_fn: fn(&mut AppDeps, Env, H) -> StdResult<HandleResponse>,
+    query_fn: fn(&AppDeps, Q) -> StdResult<Binary>,
+}
+
+impl<I, H, Q> ContractWrapper<I, H, Q> {
+    pub fn new(
+        init_fn: fn(&mut AppDeps, Env, I) -> StdResult<InitResponse>,
+        handle_fn: fn(&mut AppDeps, Env, H) -> StdResult<HandleResponse>,
+        query_fn: fn(&AppDeps, Q) -> StdResult<Binary>,
+    ) -> Self {
+        ContractWrapper {
+            init_fn,
+            handle_fn,
+            query_fn,
+        }
+    }
+}
+
+impl<I, H, Q> Contract for ContractWrapper<I, H, Q>
+where
+    I: DeserializeOwned,
+    H: DeserializeOwned,
+    Q: DeserializeOwned,
+{
+    fn init(&self, deps: &mut AppDeps, env: Env, msg: &[u8]) -> StdResult<InitResponse> {
+        (self.init_fn)(deps, env, from_slice(msg)?)
+    }
+
+    fn handle(&self, deps: &mut AppDeps, env: Env, msg: &[u8]) -> StdResult<HandleResponse> {
+        (self.handle_fn)(deps, env, from_slice(msg)?)
+    }
+
+    fn query(&self, deps: &AppDeps, msg: &[u8]) -> StdResult<Binary> {
+        (self.query_fn)(deps, from_slice(msg)?)
+    }
+}
+
+struct Instance {
+    code_id: u64,
+    storage: AppStorage,
+}
+
+#[derive(Default)]
+struct Registry {
+    codes: Vec<Rc<dyn Contract>>,
+    contracts: HashMap<HumanAddr, Instance>,
+}
+
+impl Registry {
+    fn instance(&self, addr: &HumanAddr) -> Option<(Rc<dyn Contract>, AppStorage)> {
+        self.contracts.get(addr).map(|instance| {
+            (
+                self.codes[instance.code_id as usize - 1].clone(),
+                instance.storage.clone(),
+            )
+        })
+    }
+}
+
+/// Answers contract queries from the instances held by an `App`
+#[derive(Clone)]
+pub struct AppQuerier {
+    registry: Rc<RefCell<Registry>>,
+}
+
+impl AppQuerier {
+    fn query_wasm(&self, request: WasmQuery) -> QuerierResult {
+        let addr = match &request {
+            WasmQuery::Smart { contract_addr, .. } => contract_addr,
+            WasmQuery::Raw { contract_addr, .. } => contract_addr,
+        };
+        let (code, storage) = match self.registry.borrow().instance(addr) {
+            Some(instance) => instance,
+            None => return Err(SystemError::NoSuchContract { addr: addr.clone() }),
+        };
+
+        match request {
+            WasmQuery::Smart { msg, .. } => {
+                let deps = Extern {
+                    storage,
+                    api: MockApi::new(CANONICAL_LENGTH),
+                    querier: self.clone(),
+                };
+                Ok(code.query(&deps, msg.as_slice()))
+            }
+            WasmQuery::Raw { key, .. } => {
+                Ok(Ok(Binary(storage.get(key.as_slice()).unwrap_or_default())))
+            }
+        }
+    }
+}
+
+impl Querier for AppQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let request: QueryRequest<Empty> = match from_slice(bin_request) {
+            Ok(request) => request,
+            Err(err) => {
+                return Err(SystemError::InvalidRequest {
+                    error: err.to_string(),
+                    request: Binary::from(bin_request),
+                })
+            }
+        };
+
+        match request {
+            QueryRequest::Wasm(wasm) => self.query_wasm(wasm),
+            _ => Err(SystemError::UnsupportedRequest {
+                kind: "only wasm queries are supported".to_string(),
+            }),
+        }
+    }
+}
+
+/// A set of contract instances that call and query each other in memory
+pub struct App {
+    registry: Rc<RefCell<Registry>>,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App {
+    pub fn new() -> Self {
+        App {
+            registry: Rc::new(RefCell::new(Registry::default())),
+        }
+    }
+
+    /// Registers contract code and returns its code id. Code ids start at 1.
+    pub fn store_code(&mut self, code: Box<dyn Contract>) -> u64 {
+        let mut registry = self.registry.borrow_mut();
+        registry.codes.push(Rc::from(code));
+        registry.codes.len() as u64
+    }
+
+    /// Instantiates a contract from `sender` and processes the messages it returns.
+    /// Returns the address of the new contract.
+    pub fn instantiate<T: Serialize>(
+        &mut self,
+        code_id: u64,
+        sender: &HumanAddr,
+        msg: &T,
+    ) -> StdResult<HumanAddr> {
+        let msg = to_binary(msg)?;
+        self.atomically(|app| {
+            let mut log = vec![];
+            app.dispatch_instantiate(sender, code_id, &msg, &mut log)
+        })
+    }
+
+    /// Executes a message on `contract_addr` from `sender` and processes the messages it
+    /// returns. Returns the logs of every contract called, in execution order.
+    pub fn execute<T: Serialize>(
+        &mut self,
+        sender: &HumanAddr,
+        contract_addr: &HumanAddr,
+        msg: &T,
+    ) -> StdResult<Vec<LogAttribute>> {
+        let msg = to_binary(msg)?;
+        self.atomically(|app| {
+            let mut log = vec![];
+            app.dispatch_execute(sender, contract_addr, &msg, &mut log)?;
+            Ok(log)
+        })
+    }
+
+    /// Sends a smart query to `contract_addr`
+    pub fn query<T: Serialize, U: DeserializeOwned>(
+        &self,
+        contract_addr: &HumanAddr,
+        msg: &T,
+    ) -> StdResult<U> {
+        self.querier().query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: contract_addr.clone(),
+            msg: to_binary(msg)?,
+        }))
+    }
+
+    fn querier(&self) -> AppQuerier {
+        AppQuerier {
+            registry: self.registry.clone(),
+        }
+    }
+
+    fn deps(&self, storage: AppStorage) -> AppDeps {
+        Extern {
+            storage,
+            api: MockApi::new(CANONICAL_LENGTH),
+            querier: self.querier(),
+        }
+    }
+
+    fn env(sender: &HumanAddr, contract_addr: &HumanAddr) -> Env {
+        let mut env = mock_env(sender.clone(), &[]);
+        env.contract.address = contract_addr.clone();
+        env
+    }
+
+    fn instance(&self, addr: &HumanAddr) -> StdResult<(Rc<dyn Contract>, AppStorage)> {
+        self.registry
+            .borrow()
+            .instance(addr)
+            .ok_or_else(|| StdError::not_found(format!("contract {}", addr)))
+    }
+
+    /// Runs `action`, restoring every contract's storage if it fails
+    fn atomically<T, F>(&mut self, action: F) -> StdResult<T>
+    where
+        F: FnOnce(&mut Self) -> StdResult<T>,
+    {
+        let snapshot: HashMap<HumanAddr, BTreeMap<Vec<u8>, Vec<u8>>> = self
+            .registry
+            .borrow()
+            .contracts
+            .iter()
+            .map(|(addr, instance)| (addr.clone(), instance.storage.0.borrow().clone()))
+            .collect();
+
+        let res = action(self);
+        if res.is_err() {
+            let mut registry = self.registry.borrow_mut();
+            registry
+                .contracts
+                .retain(|addr, _| snapshot.contains_key(addr));
+            for (addr, data) in snapshot {
+                *registry.contracts[&addr].storage.0.borrow_mut() = data;
+            }
+        }
+        res
+    }
+
+    fn dispatch_instantiate(
+        &mut self,
+        sender: &HumanAddr,
+        code_id: u64,
+        msg: &Binary,
+        log: &mut Vec<LogAttribute>,
+    ) -> StdResult<HumanAddr> {
+        let (code, storage, addr) = {
+            let mut registry = self.registry.borrow_mut();
+            if code_id == 0 || code_id as usize > registry.codes.len() {
+                return Err(StdError::not_found(format!("code id {}", code_id)));
+            }
+            let addr = HumanAddr(format!("contract{}", registry.contracts.len() + 1));
+            let storage = AppStorage::default();
+            registry.contracts.insert(
+                addr.clone(),
+                Instance {
+                    code_id,
+                    storage: storage.clone(),
+                },
+            );
+            (registry.codes[code_id as usize - 1].clone(), storage, addr)
+        };
+
+        let mut deps = self.deps(storage);
+        let res = code.init(&mut deps, Self::env(sender, &addr), msg.as_slice())?;
+        log.extend(res.log);
+        self.dispatch_messages(&addr, res.messages, log)?;
+        Ok(addr)
+    }
+
+    fn dispatch_execute(
+        &mut self,
+        sender: &HumanAddr,
+        contract_addr: &HumanAddr,
+        msg: &Binary,
+        log: &mut Vec<LogAttribute>,
+    ) -> StdResult<()> {
+        let (code, storage) = self.instance(contract_addr)?;
+
+        let mut deps = self.deps(storage);
+        let res = code.handle(&mut deps, Self::env(sender, contract_addr), msg.as_slice())?;
+        log.extend(res.log);
+        self.dispatch_messages(contract_addr, res.messages, log)
+    }
+
+    fn dispatch_messages(
+        &mut self,
+        sender: &HumanAddr,
+        msgs: Vec<CosmosMsg>,
+        log: &mut Vec<LogAttribute>,
+    ) -> StdResult<()> {
+        for msg in msgs {
+            match msg {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    msg,
+                    send,
+                }) => {
+                    if !send.is_empty() {
+                        return Err(StdError::generic_err("sending funds is not supported"));
+                    }
+                    self.dispatch_execute(sender, &contract_addr, &msg, log)?;
+                }
+                CosmosMsg::Wasm(WasmMsg::Instantiate {
+                    code_id, msg, send, ..
+                }) => {
+                    if !send.is_empty() {
+                        return Err(StdError::generic_err("sending funds is not supported"));
+                    }
+                    self.dispatch_instantiate(sender, code_id, &msg, log)?;
+                }
+                msg => {
+                    return Err(StdError::generic_err(format!(
+                        "unsupported message: {:?}",
+                        msg
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+}
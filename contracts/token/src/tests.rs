@@ -3,7 +3,7 @@ use cosmwasm_std::{Uint128, log, testing::{mock_dependencies, mock_env}};
 use cosmwasm_std::{coins, from_binary, HumanAddr, StdError};
 use cw20::{MinterResponse, BalanceResponse};
 
-use axelar_gateway::{hook::InitHook, token::{HandleMsg, InitMsg, QueryMsg}};
+use axelar_gateway_contracts::{hook::InitHook, token::{HandleMsg, InitMsg, QueryMsg}};
 
 #[test]
 fn initialization() {
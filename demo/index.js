@@ -39,8 +39,6 @@ const validate_schema = (...args) => validator.validate(...args);
 const Info = chalk.blueBright;
 const Success = chalk.greenBright;
 const Err = chalk.redBright;
-const COMPRESSED_BASE64_PUB_KEY =
-  "WzMsODQsMTA5LDQwLDEwMiwyMTEsMjI3LDEyMyw0MCwxMjAsNjYsMTk4LDU5LDEwMiwxNDYsMjUwLDQ3LDM5LDE2MiwyNDYsMTQ0LDIyNywyNiwxNjUsNTYsMTg0LDMxLDEyNSw2NCwyOSwxMTgsMTM5LDI0N10=";
 
 const txMustSucceed = (r, kind = "transaction") => {
   if (isTxError(r)) {
@@ -273,8 +271,7 @@ async function deployAxelarTransferContracts(
 
     addresses[AXELAR_GATEWAY] = await init_contract(AXELAR_GATEWAY, {
       owner: wallet.key.accAddress,
-      // public_key: wallet.key.rawPubKey.toString('base64'),
-      public_key: COMPRESSED_BASE64_PUB_KEY,
+      public_key: { bech32: wallet.key.accPubKey },
       crypto_contract_addr: addresses[AXELAR_CRYPTO],
     });
     logDeployed(AXELAR_GATEWAY, addresses[AXELAR_GATEWAY]);
@@ -1,6 +1,7 @@
 #![allow(clippy::field_reassign_with_default)] // see https://github.com/CosmWasm/cosmwasm/issues/685
 
-use cosmwasm_std::{Api, Binary, Extern, Querier, Storage};
+use cosmwasm_std::{Api, Binary, Extern, HumanAddr, Querier, Storage, Uint128};
+use cw2::ContractVersion;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -8,7 +9,21 @@ use serde::{Deserialize, Serialize};
 pub struct InitMsg {}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct HandleMsg {}
+#[serde(rename_all = "snake_case")]
+pub enum HandleMsg {
+    /// Replay protection for signed meta-transactions.
+    /// Verifies a secp256k1 signature of `digest` and records the digest as consumed in the
+    /// namespace of the calling contract. Fails if the signature does not verify, or if the
+    /// caller consumed the digest before.
+    ConsumeSignature {
+        /// Message digest (32 bytes) that was signed, e.g. `sha256(message)`.
+        digest: Binary,
+        /// Serialized signature. Cosmos format (64 bytes).
+        signature: Binary,
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) public key.
+        pubkey: Binary,
+    },
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -23,9 +38,200 @@ pub enum QueryMsg {
         public_key: Binary,
     },
 
-    /// Cosmos Address Recovery
+    /// Cosmos ADR-036 offchain signature (secp256k1 verification scheme), as created by Keplr's
+    /// `signArbitrary` or the Ledger Cosmos app.
+    /// The amino JSON `StdSignDoc` with a single `sign/MsgSignData` message and an empty chain id
+    /// is rebuilt from `signer` and `data`, and its SHA-256 hash is verified.
+    VerifyAdr036Signature {
+        /// Bech32 account address of the signer. Must match `public_key`.
+        signer: String,
+        /// Signed data.
+        data: Binary,
+        /// Serialized signature. Cosmos format (64 bytes).
+        signature: Binary,
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) public key.
+        public_key: Binary,
+    },
+
+    /// secp256k1 verification with an explicit message hash function.
+    VerifySecp256k1Signature {
+        /// Message to verify. Must be the 32-byte digest itself when `hash_function` is `none`.
+        message: Binary,
+        /// Serialized signature. Cosmos format (64 bytes).
+        signature: Binary,
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) public key.
+        public_key: Binary,
+        /// Hash function applied to `message` before verification.
+        hash_function: HashFunction,
+    },
+
+    /// secp256k1 batch verification. Three shapes are supported:
+    /// - Equal number of messages, signatures, and public keys.
+    /// - One message, and an equal number of signatures and public keys (shared message).
+    /// - One public key, and an equal number of messages and signatures (shared key).
+    ///
+    /// Returns a `VerifySecp256k1BatchResponse`.
+    VerifySecp256k1Batch {
+        /// Messages to verify. Must be 32-byte digests when `hash_function` is `none`.
+        messages: Vec<Binary>,
+        /// Serialized signatures. Cosmos format (64 bytes).
+        signatures: Vec<Binary>,
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) public keys.
+        public_keys: Vec<Binary>,
+        /// Hash function applied to each message before verification.
+        hash_function: HashFunction,
+    },
+
+    /// Weighted k-of-n secp256k1 multisig verification.
+    /// Returns a `VerifyThresholdResponse`.
+    VerifyThreshold {
+        /// Message that was signed. Must be the 32-byte digest itself when `hash_function` is `none`.
+        message: Binary,
+        /// Hash function applied to `message` before verification.
+        hash_function: HashFunction,
+        /// The signer set.
+        signers: Vec<WeightedSigner>,
+        /// Minimum total weight of verified signers. Must be greater than zero.
+        threshold: Uint128,
+        /// Signatures, each referencing its signer by index into `signers`.
+        /// Each signer may sign at most once.
+        signatures: Vec<SignerSignature>,
+    },
+
+    /// Recovers the secp256k1 public key that produced a recoverable signature.
+    /// Returns a `RecoverPubkeyResponse`.
+    RecoverPubkey {
+        /// Message that was signed. Must be the 32-byte digest itself when `hash_function` is `none`.
+        message: Binary,
+        /// Serialized signature. Fixed length format (64 bytes `r` and `s` plus the one byte `v`).
+        /// `v` is the recovery id, either 0/1 or 27/28.
+        signature: Binary,
+        /// Hash function applied to `message` before recovery.
+        hash_function: HashFunction,
+    },
+
+    /// Cosmos Address Recovery.
+    /// Recovers the signer's public key and derives its bech32 account address.
+    /// Returns a `RecoverCosmosAddressResponse`.
     RecoverCosmosAddress {
-        /// Message to verify
+        /// Message that was signed. Must be the 32-byte digest itself when `hash_function` is `none`.
+        message: Binary,
+        /// Serialized signature. Fixed length format (64 bytes `r` and `s` plus the one byte `v`).
+        /// `v` is the recovery id, either 0/1 or 27/28.
+        signature: Binary,
+        /// Hash function applied to `message` before recovery. Cosmos signers use `sha256`.
+        hash_function: HashFunction,
+        /// Bech32 prefix of the address, e.g. `terra` or `cosmos`.
+        prefix: String,
+    },
+
+    /// P-256 (secp256r1) ECDSA verification, as produced by HSMs and passkeys.
+    VerifySecp256r1Signature {
+        /// Message to verify. It will be hashed with SHA-256 before verification.
+        message: Binary,
+        /// Serialized signature. Fixed length format (64 bytes `r` and `s`).
+        /// High `s` values are accepted.
+        signature: Binary,
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) SEC1 public key.
+        public_key: Binary,
+    },
+
+    /// WebAuthn assertion verification (P-256, COSE algorithm ES256), e.g. from a browser
+    /// security key or passkey.
+    /// The signature is verified over `authenticator_data || sha256(client_data_json)`.
+    VerifyWebauthnAssertion {
+        /// Raw authenticator data of the assertion.
+        authenticator_data: Binary,
+        /// Raw clientDataJSON of the assertion, as signed.
+        client_data_json: Binary,
+        /// Serialized signature. ASN.1 DER format, as returned by the authenticator.
+        signature: Binary,
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) SEC1 public key of the credential.
+        public_key: Binary,
+        /// Expected challenge. Matched against the base64url encoded `challenge` of the client data.
+        challenge: Binary,
+        /// Relying party id, e.g. `example.com`. Its SHA-256 hash must lead the authenticator data.
+        rp_id: String,
+        /// Expected origin, e.g. `https://example.com`. Not checked if unset.
+        origin: Option<String>,
+        /// Require the user verified (UV) flag in addition to the user present (UP) flag.
+        require_user_verification: bool,
+    },
+
+    /// BIP-340 Schnorr verification (secp256k1), as used by Taproot.
+    VerifySchnorrSignature {
+        /// Message to verify. Usually a 32-byte digest; other lengths are accepted as in BIP-340.
+        message: Binary,
+        /// Serialized signature (64 bytes, `r` and `s`).
+        signature: Binary,
+        /// Serialized x-only public key (32 bytes).
+        public_key: Binary,
+    },
+
+    /// BLS12-381 verification. The group convention follows the signature length:
+    /// - 96 byte (G2) signature and 48 byte (G1) public key, e.g. Ethereum consensus.
+    /// - 48 byte (G1) signature and 96 byte (G2) public key.
+    VerifyBlsSignature {
+        /// Message to verify. It is hashed to the curve with `dst`.
+        message: Binary,
+        /// Serialized compressed signature (96 or 48 bytes).
+        signature: Binary,
+        /// Serialized compressed public key (48 or 96 bytes).
+        public_key: Binary,
+        /// Domain separation tag of the hash to curve.
+        /// Defaults to the proof of possession ciphersuite `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_`
+        /// (G2 signatures) or `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_` (G1 signatures).
+        dst: Option<Binary>,
+    },
+
+    /// BLS12-381 aggregate signature verification. Two shapes are supported:
+    /// - One message, and any number of public keys (all keys signed the same message).
+    ///   Only safe if possession of the keys has been proven.
+    /// - One message per public key. The messages must be distinct.
+    VerifyBlsAggregate {
+        /// Messages to verify.
+        messages: Vec<Binary>,
+        /// Serialized compressed aggregate signature (96 or 48 bytes).
+        signature: Binary,
+        /// Serialized compressed public keys (48 or 96 bytes each).
+        public_keys: Vec<Binary>,
+        /// Domain separation tag of the hash to curve. Same defaults as `VerifyBlsSignature`.
+        dst: Option<Binary>,
+    },
+
+    /// Tendermint format (ed25519 verification scheme).
+    VerifyTendermintSignature {
+        /// Message to verify.
+        message: Binary,
+        /// Serialized signature. Tendermint format (64 bytes).
+        signature: Binary,
+        /// Serialized public key. Tendermint format (32 bytes).
+        public_key: Binary,
+    },
+
+    /// Tendermint format (ed25519 batch verification scheme).
+    /// Three shapes are supported:
+    /// - Equal number of messages, signatures, and public keys.
+    /// - One message, and an equal number of signatures and public keys (multisig of a single message).
+    /// - One public key, and an equal number of messages and signatures.
+    ///
+    /// Returns a `VerifyBatchResponse`.
+    VerifyTendermintBatch {
+        /// Messages to verify.
+        messages: Vec<Binary>,
+        /// Serialized signatures. Tendermint format (64 bytes).
+        signatures: Vec<Binary>,
+        /// Serialized public keys. Tendermint format (32 bytes).
+        public_keys: Vec<Binary>,
+    },
+
+    /// Ethereum text verification (compatible to the eth_sign RPC/web3 endpoint).
+    /// This cannot be used to verify transactions.
+    ///
+    /// See https://web3js.readthedocs.io/en/v1.2.0/web3-eth.html#sign
+    VerifyEthereumText {
+        /// Message to verify. This will be wrapped in the standard container
+        /// `"\x19Ethereum Signed Message:\n" + len(message) + message` before verification.
         message: String,
         /// Serialized signature. Fixed length format (64 bytes `r` and `s` plus the one byte `v`).
         signature: Binary,
@@ -33,17 +239,488 @@ pub enum QueryMsg {
         /// This is matched case insensitive, so you can provide checksummed and non-checksummed addresses. Checksums are not validated.
         signer_address: String,
     },
+
+    /// Bitcoin signed message verification (Bitcoin Core `signmessage`, BIP-137).
+    /// The message is hashed as `sha256(sha256("\x18Bitcoin Signed Message:\n" ||
+    /// varint(len(message)) || message))` before recovery.
+    VerifyBitcoinMessage {
+        /// Message to verify, as raw text. This will be wrapped in the standard container
+        /// `"\x18Bitcoin Signed Message:\n" + varint(len(message)) + message` before
+        /// verification, so it must not be prefixed by the caller.
+        message: String,
+        /// Serialized signature. Compact format (65 bytes): the header byte followed by `r` and
+        /// `s`, i.e. the base64 decoded signature of the wallet.
+        signature: Binary,
+        /// P2PKH (`1...`) or P2WPKH (`bc1q...`) address, on mainnet, testnet or regtest.
+        address: String,
+    },
+
+    /// Verifies the sender of a signed legacy Ethereum transaction with EIP-155 replay protection.
+    /// The transaction is RLP encoded as `[nonce, gas_price, gas_limit, to, value, data, chain_id, 0, 0]`,
+    /// hashed with Keccak-256, and the recovered sender is matched against `from`.
+    VerifyEthereumTransaction {
+        /// Ethereum address in hex format (42 characters, starting with 0x)
+        from: String,
+        /// Ethereum address in hex format (42 characters, starting with 0x).
+        /// Empty for contract creation transactions.
+        to: String,
+        nonce: u64,
+        gas_limit: Uint128,
+        gas_price: Uint128,
+        value: Uint128,
+        data: Binary,
+        /// EIP-155 chain id, see https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md#list-of-chain-ids
+        chain_id: u64,
+        /// Signature `r` value (32 bytes)
+        r: Binary,
+        /// Signature `s` value (32 bytes)
+        s: Binary,
+        /// Signature `v` value, `chain_id * 2 + 35` or `chain_id * 2 + 36`
+        v: u64,
+    },
+
+    /// Verifies the inclusion of a transaction receipt in a block's `receiptsRoot` through a
+    /// Merkle-Patricia proof, and decodes the receipt.
+    /// Returns an `EthereumReceiptResponse`.
+    VerifyEthereumReceiptProof {
+        /// `receiptsRoot` of the block header (32 bytes).
+        receipts_root: Binary,
+        /// Index of the transaction in the block.
+        tx_index: u64,
+        /// RLP encoded trie nodes from the root to the receipt.
+        proof: Vec<Binary>,
+    },
+
+    /// Merkle inclusion proof of a binary SHA-256 tree in RFC 6962 style, as used by Tendermint
+    /// (leaf hash `sha256(0x00 || leaf)`, inner hash `sha256(0x01 || left || right)`).
+    VerifyTendermintMerkleProof {
+        /// Leaf data. It will be hashed as RFC 6962 leaf.
+        leaf: Binary,
+        /// Index of the leaf in the tree.
+        index: u64,
+        /// Number of leaves in the tree.
+        total: u64,
+        /// Sibling hashes (32 bytes each) from the leaf up to the root.
+        aunts: Vec<Binary>,
+        /// Root hash (32 bytes).
+        root: Binary,
+    },
+
+    /// Merkle inclusion proof of a Keccak-256 tree with sorted pairs, as verified by
+    /// OpenZeppelin's `MerkleProof`.
+    VerifyKeccakMerkleProof {
+        /// Leaf hash (32 bytes), e.g. `keccak256(abi.encodePacked(account, amount))`.
+        leaf: Binary,
+        /// Sibling hashes (32 bytes each) from the leaf up to the root.
+        proof: Vec<Binary>,
+        /// Root hash (32 bytes).
+        root: Binary,
+    },
+
+    /// Converts a secp256k1 SEC1 public key to its compressed form (33 bytes).
+    /// Returns a `PubkeyResponse`.
+    CompressPubkey {
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) SEC1 public key.
+        public_key: Binary,
+    },
+
+    /// Converts a secp256k1 SEC1 public key to its uncompressed form (65 bytes).
+    /// Returns a `PubkeyResponse`.
+    DecompressPubkey {
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) SEC1 public key.
+        public_key: Binary,
+    },
+
+    /// Derives the bech32 account address of a secp256k1 public key,
+    /// `ripemd160(sha256(compressed_public_key))`.
+    /// Returns an `AddressResponse`.
+    DeriveCosmosAddress {
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) SEC1 public key.
+        public_key: Binary,
+        /// Bech32 prefix of the address, e.g. `terra` or `cosmos`.
+        prefix: String,
+    },
+
+    /// Derives the Ethereum address of a secp256k1 public key, the last 20 bytes of
+    /// `keccak256(uncompressed_public_key)`, with EIP-55 checksum.
+    /// Returns an `AddressResponse`.
+    DeriveEthereumAddress {
+        /// Serialized compressed (33 bytes) or uncompressed (65 bytes) SEC1 public key.
+        public_key: Binary,
+    },
+
+    /// Checks that a secp256k1 SEC1 public key is well formed and a point on the curve.
+    /// Returns a `ValidatePubkeyResponse`.
+    ValidatePubkey {
+        /// Serialized public key.
+        public_key: Binary,
+    },
+
+    /// EIP-712 typed data signature verification, as created by `eth_signTypedData_v4`
+    /// (e.g. MetaMask).
+    VerifyEip712Signature {
+        domain: Eip712Domain,
+        /// Struct types of the message. `EIP712Domain` is derived from `domain` and need not
+        /// be listed.
+        types: Vec<Eip712Type>,
+        /// Name of the struct type of `message`.
+        primary_type: String,
+        /// JSON encoded message. Integers may be JSON numbers or decimal or 0x prefixed hex
+        /// strings, byte values 0x prefixed hex strings.
+        message: String,
+        /// Serialized signature. Fixed length format (64 bytes `r` and `s` plus the one byte `v`).
+        /// `v` must be 27 or 28.
+        signature: Binary,
+        /// Ethereum address in hex format (42 characters, starting with 0x).
+        signer_address: String,
+    },
+
+    /// Recovers the signer of EIP-712 typed data.
+    /// Returns an `Eip712SignerResponse`.
+    RecoverEip712Signer {
+        domain: Eip712Domain,
+        /// Struct types of the message. `EIP712Domain` is derived from `domain` and need not
+        /// be listed.
+        types: Vec<Eip712Type>,
+        /// Name of the struct type of `message`.
+        primary_type: String,
+        /// JSON encoded message.
+        message: String,
+        /// Serialized signature. Fixed length format (64 bytes `r` and `s` plus the one byte `v`).
+        /// `v` is the recovery id, either 0/1 or 27/28.
+        signature: Binary,
+    },
+
+    /// Hashes `data` with `algorithm`.
+    /// Returns a `HashResponse`.
+    Hash {
+        algorithm: HashAlgorithm,
+        data: Binary,
+    },
+
+    /// Hashes each item of `data` with `algorithm`.
+    /// Returns a `HashBatchResponse`.
+    HashBatch {
+        algorithm: HashAlgorithm,
+        data: Vec<Binary>,
+    },
+
+    /// Checks whether `caller` consumed `digest` with `ConsumeSignature`.
+    /// Returns a `DigestConsumedResponse`.
+    IsDigestConsumed {
+        /// Address of the contract that consumed the signature.
+        caller: HumanAddr,
+        /// Message digest (32 bytes).
+        digest: Binary,
+    },
+
     /// Returns a list of supported verification schemes.
     /// No pagination - this is a short list.
     ListVerificationSchemes {},
+
+    /// Returns the contract version and what each verification scheme supports.
+    /// Returns a `CapabilitiesResponse`.
+    Capabilities {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WeightedSigner {
+    /// Serialized compressed (33 bytes) or uncompressed (65 bytes) public key.
+    pub public_key: Binary,
+    /// Must be greater than zero. The weights of a signer set must not overflow `u128`.
+    pub weight: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignerSignature {
+    /// Index of the signer in the signer set.
+    pub signer_index: u32,
+    /// Serialized signature. Cosmos format (64 bytes).
+    pub signature: Binary,
+}
+
+/// Hash function used to compute the digest that was signed.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HashFunction {
+    /// SHA-256, as used by Cosmos SDK and most secp256k1 signers.
+    Sha256,
+    /// Keccak-256, as used by Ethereum.
+    Keccak256,
+    /// The message is already a 32-byte digest and is verified as is.
+    None,
+}
+
+/// Encoding of the keys (or addresses) that identify signers of a verification scheme.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyEncoding {
+    /// Compressed SEC1 point (33 bytes).
+    Sec1Compressed,
+    /// Uncompressed SEC1 point (65 bytes).
+    Sec1Uncompressed,
+    /// BIP-340 x-only public key (32 bytes).
+    XOnly,
+    /// ed25519 public key (32 bytes).
+    Ed25519,
+    /// Compressed BLS12-381 G1 point (48 bytes).
+    BlsG1,
+    /// Compressed BLS12-381 G2 point (96 bytes).
+    BlsG2,
+    /// Ethereum address (20 bytes), the signer's key is recovered.
+    EthereumAddress,
+    /// Bitcoin P2PKH or P2WPKH address (20 byte key hash), the signer's key is recovered.
+    BitcoinAddress,
+}
+
+/// How a verification scheme turns the message into what is signed.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HashMode {
+    /// The message is hashed with SHA-256.
+    Sha256,
+    /// The message is hashed with Keccak-256.
+    Keccak256,
+    /// The message is a 32-byte digest and is verified as is (`HashFunction::None`).
+    Prehashed,
+    /// The message is signed as is and hashed by the signature scheme itself, e.g. ed25519,
+    /// BIP-340 or BLS hash to curve.
+    Native,
+    /// The signed payload is rebuilt from structured input, e.g. an ADR-036 sign doc, an EIP-191
+    /// text message, an RLP encoded transaction, EIP-712 typed data or a WebAuthn assertion.
+    Structured,
+}
+
+/// What a verification scheme of `ListVerificationSchemes` supports.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SchemeCapabilities {
+    /// Name of the scheme, as listed by `ListVerificationSchemes`.
+    pub scheme: String,
+    pub key_encodings: Vec<KeyEncoding>,
+    pub hash_modes: Vec<HashMode>,
+    /// True if several signatures are verified with a single query.
+    pub batch: bool,
+    /// Maximum length of a serialized signature in bytes.
+    pub max_signature_len: u32,
+    /// Maximum length of a serialized public key (or address) in bytes.
+    pub max_public_key_len: u32,
+    /// Maximum number of signatures of a batch. Not set if only limited by gas.
+    pub max_batch_size: Option<u32>,
+}
+
+/// EIP-712 domain. Fields that are not set are not part of the domain type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Eip712Domain {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub chain_id: Option<Uint128>,
+    /// Ethereum address in hex format (42 characters, starting with 0x).
+    pub verifying_contract: Option<String>,
+    /// 32 bytes.
+    pub salt: Option<Binary>,
+}
+
+/// EIP-712 struct type, e.g. `Person` with the fields `string name` and `address wallet`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Eip712Type {
+    pub name: String,
+    pub fields: Vec<Eip712Field>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Eip712Field {
+    pub name: String,
+    /// Solidity type, e.g. `uint256`, `bytes32[]` or the name of another struct type.
+    #[serde(rename = "type")]
+    pub field_type: String,
+}
+
+/// Hash algorithm of the hashing service.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HashAlgorithm {
+    /// SHA-256 (32 bytes), as used by the gateway for message batch digests.
+    Sha256,
+    /// SHA-512 (64 bytes).
+    Sha512,
+    /// Keccak-256 (32 bytes), as used by Ethereum.
+    Keccak256,
+    /// RIPEMD-160 (20 bytes).
+    Ripemd160,
+    /// BLAKE2b-512 (64 bytes).
+    Blake2b,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct VerifyResponse {
     pub verifies: bool,
+    /// Why the inputs could not be verified, e.g. a malformed public key. Not set if the inputs
+    /// are well formed, whether or not the signature verifies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<FailureReason>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FailureReason {
+    /// Stable error code. Codes below 100 are the ones of the chain's crypto API, e.g.
+    /// 3 invalid hash format, 4 invalid signature format, 5 invalid public key format.
+    /// Codes from 100 up are contract specific: 100 invalid address, 101 invalid signature
+    /// encoding, 102 invalid input, 103 invalid Merkle proof.
+    pub code: u32,
+    /// Human readable description, e.g. `Invalid public key format`.
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EntryFailure {
+    /// Index of the malformed entry: of the signature in batches, of the signer in threshold
+    /// queries.
+    pub index: u32,
+    pub failure_reason: FailureReason,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifyBatchResponse {
+    pub verifies: bool,
+    /// Indices (in signature order) of the signatures that do not verify. Empty if `verifies`.
+    pub failed_indices: Vec<u32>,
+    /// Why the batch could not be verified, e.g. mismatched numbers of messages and
+    /// signatures. Not set if the batch is well formed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<FailureReason>,
+    /// Why malformed entries failed, in signature order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entry_failures: Vec<EntryFailure>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifySecp256k1BatchResponse {
+    /// True if all signatures verify.
+    pub verifies: bool,
+    /// Verification result of each signature, in signature order.
+    pub results: Vec<bool>,
+    /// Why the batch could not be verified, e.g. mismatched numbers of messages and
+    /// signatures. Not set if the batch is well formed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<FailureReason>,
+    /// Why malformed entries failed, in signature order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entry_failures: Vec<EntryFailure>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifyThresholdResponse {
+    /// True if the total weight of the verified signers reaches the threshold.
+    pub threshold_met: bool,
+    /// Total weight of the verified signers.
+    pub signed_weight: Uint128,
+    /// Indices of the signers whose signature verified, in signature order.
+    pub verified_signers: Vec<u32>,
+    /// Why the signatures could not be checked, e.g. a zero threshold or a duplicate signer.
+    /// Not set if the signer set and signatures are well formed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<FailureReason>,
+    /// Why signers with a malformed signature or public key failed, in signature order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entry_failures: Vec<EntryFailure>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RecoverPubkeyResponse {
+    /// Uncompressed (65 bytes) SEC1 public key.
+    pub public_key: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RecoverCosmosAddressResponse {
+    /// Bech32 account address, `ripemd160(sha256(public_key))` with the requested prefix.
+    pub address: String,
+    /// Compressed (33 bytes) SEC1 public key.
+    pub public_key: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EthereumReceiptResponse {
+    /// True if the proof shows the receipt is in the receipts root.
+    pub verifies: bool,
+    /// Why the proof could not be verified, e.g. a node hash mismatch or a malformed receipt.
+    /// Not set if the proof is well formed, whether or not the receipt is in the root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<FailureReason>,
+    /// The decoded receipt. Only set if `verifies`.
+    pub receipt: Option<EthereumReceipt>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EthereumReceipt {
+    /// EIP-2718 transaction type, 0 for legacy transactions.
+    pub tx_type: u8,
+    /// True if the transaction succeeded.
+    pub success: bool,
+    pub cumulative_gas_used: Uint128,
+    /// Logs emitted by the transaction, in order.
+    pub logs: Vec<EthereumLog>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EthereumLog {
+    /// Address of the emitting contract in hex format (42 characters, starting with 0x).
+    pub address: String,
+    /// Indexed topics (32 bytes each).
+    pub topics: Vec<Binary>,
+    pub data: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PubkeyResponse {
+    /// Serialized SEC1 public key.
+    pub public_key: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AddressResponse {
+    pub address: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ValidatePubkeyResponse {
+    /// True if the public key is well formed and a point on the curve.
+    pub valid: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Eip712SignerResponse {
+    /// Signer's address in hex format with EIP-55 checksum.
+    pub address: String,
+    /// EIP-712 signing hash (32 bytes) of the typed data.
+    pub signing_hash: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct HashResponse {
+    pub hash: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct HashBatchResponse {
+    /// Hashes in input order.
+    pub hashes: Vec<Binary>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DigestConsumedResponse {
+    pub consumed: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ListVerificationsResponse {
     pub verification_schemes: Vec<String>,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CapabilitiesResponse {
+    /// Contract name and version, as stored with cw2.
+    pub version: ContractVersion,
+    /// Capabilities of each verification scheme, in `ListVerificationSchemes` order.
+    pub schemes: Vec<SchemeCapabilities>,
+}
@@ -10,8 +10,55 @@ use serde::{Deserialize, Serialize};
 // Axelar must query the owner address and pubkey
 pub struct InitMsg {
     pub owner: HumanAddr,
-    pub public_key: String,
+    pub public_key: PublicKey,
     pub crypto_contract_addr: HumanAddr,
+    /// Curve of `public_key`, secp256k1 if not set.
+    pub key_type: Option<KeyType>,
+}
+
+/// An owner public key tagged with the encoding it was supplied in. The format is
+/// never guessed: the variant name selects the decoder.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PublicKey {
+    /// Standard base64 of the SEC1 bytes, compressed (33 bytes) or uncompressed (65 bytes).
+    Base64(String),
+    /// Hex of the SEC1 bytes, compressed or uncompressed, with or without a `0x` prefix.
+    Hex(String),
+    /// Terra account public key in bech32 (`terrapub1...`), as returned by terra.js
+    /// `Key.accPubKey`. Only for secp256k1 keys.
+    Bech32(String),
+}
+
+impl PublicKey {
+    /// Name of the encoding, used in validation errors.
+    pub fn format(&self) -> &'static str {
+        match self {
+            PublicKey::Base64(_) => "base64",
+            PublicKey::Hex(_) => "hex",
+            PublicKey::Bech32(_) => "bech32",
+        }
+    }
+}
+
+/// Curve of the owner's public key.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyType {
+    /// secp256k1, as used by Cosmos SDK keys.
+    Secp256k1,
+    /// P-256 (secp256r1), as used by HSMs and passkeys.
+    Secp256r1,
+}
+
+impl KeyType {
+    /// Name of the curve, used in validation errors.
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyType::Secp256k1 => "secp256k1",
+            KeyType::Secp256r1 => "secp256r1",
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -29,6 +76,8 @@ where
     /// Same as Execute except authorization is performed by verifying the provided
     /// signature was created by the contract owner. ExecuteSigned messages can be
     /// sent by anyone.
+    /// The signature is a 64-byte ECDSA signature (`r || s`) with the owner's key over the
+    /// SHA-256 digest of the JSON encoded messages concatenated with the big-endian gateway nonce.
     ExecuteSigned { sig: Vec<u8>, msgs: Vec<CosmosMsg<T>>, register: Vec<String> },
 
     /// Receive hook from instantiated contract to register its address
@@ -39,7 +88,12 @@ where
 
     /// UpdateOwner will change the admin set of the contract, must be called by the existing
     /// owner, and only works if the contract is mutable.
-    UpdateOwner { owner: HumanAddr, public_key: String },
+    /// The curve of `public_key` is given by `key_type`, secp256k1 if not set.
+    UpdateOwner {
+        owner: HumanAddr,
+        public_key: PublicKey,
+        key_type: Option<KeyType>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -67,6 +121,7 @@ where
 pub struct ConfigResponse {
     pub owner: HumanAddr,
     pub public_key: Vec<u8>,
+    pub key_type: KeyType,
     pub crypto_contract_addr: HumanAddr,
     pub nonce: u64, 
     pub mutable: bool,
@@ -75,6 +130,9 @@ pub struct ConfigResponse {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct CanSendResponse {
     pub can_send: bool,
+    /// Why the signature was rejected if it is malformed, e.g. `Invalid signature format (code 4)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]